@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use crate::structs::{AgentDetails, JobData};
+use crate::structs::{AgentDeliveryMetrics, AgentDetails, EscrowData, JobData, ProofRecord};
 
 /// Cross-contract storage reads shared across contracts.
 #[multiversx_sc::module]
@@ -20,6 +20,30 @@ pub trait CrossContractModule {
         job_id: &ManagedBuffer,
     ) -> SingleValueMapper<JobData<Self::Api>, ManagedAddress>;
 
+    /// Read the job's proof history from validation-registry's `jobProofs` storage.
+    #[storage_mapper_from_address("jobProofs")]
+    fn external_job_proofs(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> VecMapper<ProofRecord<Self::Api>, ManagedAddress>;
+
+    /// Read the job's service ID from validation-registry's `jobServiceId` storage.
+    #[storage_mapper_from_address("jobServiceId")]
+    fn external_job_service_id(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<u32, ManagedAddress>;
+
+    /// Read the job's service payment from validation-registry's `jobPayment` storage.
+    #[storage_mapper_from_address("jobPayment")]
+    fn external_job_payment(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<Payment<Self::Api>, ManagedAddress>;
+
     /// Read agent service config from identity-registry's `agentServiceConfigs` storage.
     #[storage_mapper_from_address("agentServiceConfigs")]
     fn external_agent_service_config(
@@ -42,4 +66,44 @@ pub trait CrossContractModule {
         address: ManagedAddress,
         nonce: u64,
     ) -> SingleValueMapper<AgentDetails<Self::Api>, ManagedAddress<Self::Api>>;
+
+    /// Read escrow record from escrow's `escrowData` storage.
+    #[storage_mapper_from_address("escrowData")]
+    fn external_escrow_data(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<EscrowData<Self::Api>, ManagedAddress>;
+
+    /// Read feedback flag from reputation-registry's `hasGivenFeedback` storage.
+    #[storage_mapper_from_address("hasGivenFeedback")]
+    fn external_has_given_feedback(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<bool, ManagedAddress>;
+
+    /// Read employer-rating flag from reputation-registry's `hasRatedEmployer` storage.
+    #[storage_mapper_from_address("hasRatedEmployer")]
+    fn external_has_rated_employer(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<bool, ManagedAddress>;
+
+    /// Read per-service delivery SLA from identity-registry's `agentServiceSla` storage.
+    #[storage_mapper_from_address("agentServiceSla")]
+    fn external_agent_service_sla(
+        &self,
+        address: ManagedAddress,
+        nonce: u64,
+    ) -> MapMapper<u32, DurationMillis, ManagedAddress<Self::Api>>;
+
+    /// Read delivery metrics from validation-registry's `agentDeliveryMetrics` storage.
+    #[storage_mapper_from_address("agentDeliveryMetrics")]
+    fn external_agent_delivery_metrics(
+        &self,
+        address: ManagedAddress,
+        agent_nonce: u64,
+    ) -> SingleValueMapper<AgentDeliveryMetrics, ManagedAddress>;
 }
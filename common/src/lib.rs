@@ -2,6 +2,7 @@
 
 pub mod cross_contract;
 pub mod errors;
+pub mod pagination;
 pub mod structs;
 
 pub use structs::*;
@@ -0,0 +1,10 @@
+/// Upper bound on the number of entries a paginated view reads in one call.
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Clamp a `(from, size)` request against a collection of `len` entries.
+/// Returns the 0-based `[start, end)` range to read.
+pub fn page_range(from: usize, size: usize, len: usize) -> (usize, usize) {
+    let start = from.min(len);
+    let end = start + size.min(MAX_PAGE_SIZE).min(len - start);
+    (start, end)
+}
@@ -10,6 +10,9 @@ pub enum JobStatus {
     Pending,
     Verified,
     ValidationRequested,
+    Cancelled,
+    Disputed,
+    Rejected,
 }
 
 #[type_abi]
@@ -22,6 +25,78 @@ pub struct JobData<M: ManagedTypeApi> {
     pub agent_nonce: u64,
 }
 
+/// One proof submission for a job. Appended on every `submit_proof*` call.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ProofRecord<M: ManagedTypeApi> {
+    pub proof_hash: ManagedBuffer<M>,
+    pub uri: ManagedBuffer<M>,
+    pub content_type: ManagedBuffer<M>,
+    pub submitter: ManagedAddress<M>,
+    pub timestamp: TimestampMillis,
+}
+
+/// Lifecycle timestamps of a job. Zero = not reached yet.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Default, PartialEq, Debug)]
+pub struct JobTimeline {
+    pub first_proof_at: TimestampMillis,
+    pub validation_requested_at: TimestampMillis,
+    pub verified_at: TimestampMillis,
+}
+
+/// Per-agent delivery aggregates, updated once per job when it is first verified.
+/// Delivery time is measured from job creation to the first proof.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Default, PartialEq, Debug)]
+pub struct AgentDeliveryMetrics {
+    pub delivered_jobs: u64,
+    pub total_delivery_ms: u64,
+    /// Delivered jobs whose service had an SLA.
+    pub sla_jobs: u64,
+    pub on_time_jobs: u64,
+}
+
+impl AgentDeliveryMetrics {
+    pub fn average_delivery_ms(&self) -> u64 {
+        self.total_delivery_ms
+            .checked_div(self.delivered_jobs)
+            .unwrap_or(0)
+    }
+
+    /// Share of SLA-bound jobs delivered on time, in basis points (10_000 = 100%).
+    pub fn on_time_ratio_bps(&self) -> u64 {
+        (self.on_time_jobs * 10_000)
+            .checked_div(self.sla_jobs)
+            .unwrap_or(0)
+    }
+}
+
+// ── Escrow types (used by escrow and validation-registry) ──
+
+/// Escrow settlement status.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum EscrowStatus {
+    Active,
+    Released,
+    Refunded,
+}
+
+/// On-chain escrow record.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct EscrowData<M: ManagedTypeApi> {
+    pub employer: ManagedAddress<M>,
+    pub receiver: ManagedAddress<M>,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub poa_hash: ManagedBuffer<M>,
+    pub deadline: TimestampSeconds,
+    pub status: EscrowStatus,
+}
+
 // ── Validation types (ERC-8004 validationRequest/Response) ──
 
 #[type_abi]
@@ -36,6 +111,17 @@ pub struct ValidationRequestData<M: ManagedTypeApi> {
     pub last_update: TimestampSeconds,
 }
 
+/// One revision of a validator's response. Appended on every `validation_response` call.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidationResponseRecord<M: ManagedTypeApi> {
+    pub response: u8,
+    pub response_uri: ManagedBuffer<M>,
+    pub response_hash: ManagedBuffer<M>,
+    pub tag: ManagedBuffer<M>,
+    pub timestamp: TimestampSeconds,
+}
+
 // ── Agent types (used by identity-registry) ──
 
 #[type_abi]
@@ -114,7 +114,8 @@ pub trait EscrowContract:
         self.escrow_released_event(&job_id, &receiver, amount);
     }
 
-    /// Refund escrowed funds to the employer if the deadline has passed.
+    /// Refund escrowed funds to the employer if the deadline has passed, or right away
+    /// if the job was rejected in a dispute on the ValidationRegistry.
     /// Anyone can call this (allows automated cleanup).
     #[endpoint(refund)]
     fn refund(&self, job_id: ManagedBuffer) {
@@ -125,7 +126,10 @@ pub trait EscrowContract:
         require!(escrow.status == EscrowStatus::Active, ERR_ALREADY_SETTLED);
 
         let current_timestamp = self.blockchain().get_block_timestamp_seconds();
-        require!(current_timestamp > escrow.deadline, ERR_DEADLINE_NOT_PASSED);
+        require!(
+            current_timestamp > escrow.deadline || self.is_job_rejected(&job_id),
+            ERR_DEADLINE_NOT_PASSED
+        );
 
         // Effects: mark as refunded BEFORE interactions
         escrow.status = EscrowStatus::Refunded;
@@ -143,4 +147,11 @@ pub trait EscrowContract:
 
         self.escrow_refunded_event(&job_id, &employer, amount);
     }
+
+    /// Cross-contract check: the job lost a dispute in the validation-registry.
+    fn is_job_rejected(&self, job_id: &ManagedBuffer) -> bool {
+        let validation_addr = self.validation_contract_address().get();
+        let job_mapper = self.external_job_data(validation_addr, job_id);
+        !job_mapper.is_empty() && job_mapper.get().status == common::structs::JobStatus::Rejected
+    }
 }
@@ -1,28 +1,7 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-/// Escrow settlement status.
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub enum EscrowStatus {
-    Active,
-    Released,
-    Refunded,
-}
-
-/// On-chain escrow record.
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub struct EscrowData<M: ManagedTypeApi> {
-    pub employer: ManagedAddress<M>,
-    pub receiver: ManagedAddress<M>,
-    pub token_id: EgldOrEsdtTokenIdentifier<M>,
-    pub token_nonce: u64,
-    pub amount: BigUint<M>,
-    pub poa_hash: ManagedBuffer<M>,
-    pub deadline: TimestampSeconds,
-    pub status: EscrowStatus,
-}
+pub use common::structs::{EscrowData, EscrowStatus};
 
 #[multiversx_sc::module]
 pub trait StorageModule {
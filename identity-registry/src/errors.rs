@@ -5,3 +5,4 @@ pub const ERR_TOKEN_ALREADY_ISSUED: &str = "Token already issued";
 pub const ERR_TOKEN_NOT_ISSUED: &str = "Token not issued";
 pub const ERR_AGENT_ALREADY_REGISTERED: &str = "Agent already registered for this address";
 pub const ERR_INVALID_NFT: &str = "Invalid NFT sent";
+pub const ERR_SERVICE_NOT_FOUND: &str = "Service config not found for agent";
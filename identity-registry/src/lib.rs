@@ -184,6 +184,20 @@ pub trait IdentityRegistry:
         self.service_configs_updated_event(nonce);
     }
 
+    /// Set the delivery SLA (time from job creation to first proof) for one of the
+    /// agent's services. Used by the validation registry for on-time metrics.
+    #[endpoint(set_service_sla)]
+    fn set_service_sla(&self, nonce: u64, service_id: u32, sla: DurationMillis) {
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        self.require_agent_owner(nonce);
+        require!(
+            self.agent_service_config(nonce).contains_key(&service_id),
+            ERR_SERVICE_NOT_FOUND
+        );
+        self.agent_service_sla(nonce).insert(service_id, sla);
+        self.service_configs_updated_event(nonce);
+    }
+
     /// Remove metadata entries by key.
     #[endpoint(remove_metadata)]
     fn remove_metadata(&self, nonce: u64, keys: MultiValueEncoded<ManagedBuffer>) {
@@ -202,8 +216,10 @@ pub trait IdentityRegistry:
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
         self.require_agent_owner(nonce);
         let mut mapper = self.agent_service_config(nonce);
+        let mut sla_mapper = self.agent_service_sla(nonce);
         for sid in service_ids {
             mapper.remove(&sid);
+            sla_mapper.remove(&sid);
         }
         self.service_configs_updated_event(nonce);
     }
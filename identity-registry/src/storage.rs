@@ -24,4 +24,8 @@ pub trait StorageModule {
     #[view(get_agent_service)]
     #[storage_mapper("agentServiceConfigs")]
     fn agent_service_config(&self, nonce: u64) -> MapMapper<u32, Payment<Self::Api>>;
+
+    /// Delivery SLA per service: time from job creation to first proof.
+    #[storage_mapper("agentServiceSla")]
+    fn agent_service_sla(&self, nonce: u64) -> MapMapper<u32, DurationMillis>;
 }
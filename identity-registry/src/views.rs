@@ -44,4 +44,9 @@ pub trait ViewsModule: crate::storage::StorageModule {
             OptionalValue::None
         }
     }
+
+    #[view(get_agent_service_sla)]
+    fn get_agent_service_sla(&self, nonce: u64, service_id: u32) -> OptionalValue<DurationMillis> {
+        self.agent_service_sla(nonce).get(&service_id).into()
+    }
 }
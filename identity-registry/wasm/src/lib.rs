@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           16
+// Endpoints:                           18
 // Async Callback:                       1
-// Total number of exported functions:  19
+// Total number of exported functions:  21
 
 #![no_std]
 
@@ -25,6 +25,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         update_agent => update_agent
         set_metadata => set_metadata
         set_service_configs => set_service_configs_endpoint
+        set_service_sla => set_service_sla
         remove_metadata => remove_metadata
         remove_service_configs => remove_service_configs
         get_agent_token_id => agent_token_id
@@ -36,6 +37,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         get_agent_owner => get_agent_owner
         get_metadata => get_metadata
         get_agent_service_config => get_agent_service_config
+        get_agent_service_sla => get_agent_service_sla
     )
 }
 
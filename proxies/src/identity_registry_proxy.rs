@@ -181,6 +181,27 @@ where
             .original_result()
     }
 
+    /// Set the delivery SLA (time from job creation to first proof) for one of the 
+    /// agent's services. Used by the validation registry for on-time metrics. 
+    pub fn set_service_sla<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<DurationMillis>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+        sla: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_service_sla")
+            .argument(&nonce)
+            .argument(&service_id)
+            .argument(&sla)
+            .original_result()
+    }
+
     /// Remove metadata entries by key. 
     pub fn remove_metadata<
         Arg0: ProxyArg<u64>,
@@ -329,4 +350,20 @@ where
             .argument(&service_id)
             .original_result()
     }
+
+    pub fn get_agent_service_sla<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DurationMillis>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_service_sla")
+            .argument(&nonce)
+            .argument(&service_id)
+            .original_result()
+    }
 }
@@ -88,29 +88,65 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    /// Simple feedback for a job. Caller must be the employer who created the job. 
-    /// Computes a cumulative moving average on-chain. 
+    /// Simple feedback for a verified job. Caller must be the employer who created the job; 
+    /// the rated agent is the job's agent. Computes a cumulative moving average on-chain. 
     pub fn give_feedback_simple<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<u64>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         job_id: Arg0,
-        agent_nonce: Arg1,
-        rating: Arg2,
+        rating: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("giveFeedbackSimple")
             .argument(&job_id)
-            .argument(&agent_nonce)
+            .argument(&rating)
+            .original_result()
+    }
+
+    /// The employer can replace their `giveFeedbackSimple` rating until the correction 
+    /// window after the original rating closes. Every score is updated as if the new 
+    /// rating had been given in the first place. 
+    pub fn correct_feedback_simple<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        new_rating: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("correctFeedbackSimple")
+            .argument(&job_id)
+            .argument(&new_rating)
+            .original_result()
+    }
+
+    /// The owner of the job's agent rates the employer once the job has an outcome 
+    /// (verified, rejected, or cancelled after a proof was submitted). Same scale, 
+    /// duplicate prevention and averaging as agent scores. 
+    pub fn rate_employer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        rating: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rateEmployer")
+            .argument(&job_id)
             .argument(&rating)
             .original_result()
     }
 
     /// ERC-8004: Anyone can give feedback (except the agent owner). 
     /// Stores raw signals — no on-chain scoring. Off-chain aggregation expected. 
+    /// `feedback_auth` is verified whenever given and mandatory if the agent requires it. 
     pub fn give_feedback<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<i64>,
@@ -120,6 +156,7 @@ where
         Arg5: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg6: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg7: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg8: ProxyArg<OptionalValue<FeedbackAuth<Env::Api>>>,
     >(
         self,
         agent_nonce: Arg0,
@@ -130,6 +167,7 @@ where
         endpoint: Arg5,
         feedback_uri: Arg6,
         feedback_hash: Arg7,
+        feedback_auth: Arg8,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -142,6 +180,24 @@ where
             .argument(&endpoint)
             .argument(&feedback_uri)
             .argument(&feedback_hash)
+            .argument(&feedback_auth)
+            .original_result()
+    }
+
+    /// Agent owner only: require a `FeedbackAuth` on every `giveFeedback` for the agent. 
+    pub fn set_feedback_auth_required<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        required: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_feedback_auth_required")
+            .argument(&agent_nonce)
+            .argument(&required)
             .original_result()
     }
 
@@ -181,8 +237,110 @@ where
             .original_result()
     }
 
-    /// ERC-8004: Anyone can append a response to feedback (e.g., agent showing refund, 
-    /// data aggregator tagging feedback as spam). 
+    /// ERC-8004 `getSummary`: number of non-revoked feedback entries for an agent and their 
+    /// average value (`summary_value / 10^summary_value_decimals`), optionally filtered by 
+    /// client set and tags. An empty tag or an empty client list means no filter. Clients 
+    /// should be distinct. Backed by aggregates; cost grows only with the client list. 
+    pub fn get_summary<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        tag1: Arg1,
+        tag2: Arg2,
+        client_addresses: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, BigInt<Env::Api>, u8>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSummary")
+            .argument(&agent_nonce)
+            .argument(&tag1)
+            .argument(&tag2)
+            .argument(&client_addresses)
+            .original_result()
+    }
+
+    pub fn get_feedback_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeedbackCount")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// ERC-8004 `readAllFeedback`, paginated: scans at most `size` entries of the agent's 
+    /// feedback log starting at `from` and returns the matching ones together with the 
+    /// cursor to resume from; the scan is complete once the cursor equals 
+    /// `getFeedbackCount`. Empty tags / client list = no filter. 
+    pub fn read_all_feedback<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<bool>,
+        Arg6: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        from: Arg1,
+        size: Arg2,
+        tag1: Arg3,
+        tag2: Arg4,
+        include_revoked: Arg5,
+        client_addresses: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, ManagedVec<Env::Api, FeedbackEntry<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("readAllFeedback")
+            .argument(&agent_nonce)
+            .argument(&from)
+            .argument(&size)
+            .argument(&tag1)
+            .argument(&tag2)
+            .argument(&include_revoked)
+            .argument(&client_addresses)
+            .original_result()
+    }
+
+    /// ERC-8004: Anyone can append a response to a feedback entry (e.g., agent showing a 
+    /// refund, data aggregator tagging feedback as spam). Responses are never overwritten. 
+    pub fn append_feedback_response<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+        response_uri: Arg3,
+        response_hash: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("appendResponse")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .argument(&response_uri)
+            .argument(&response_hash)
+            .original_result()
+    }
+
+    /// Anyone can respond to a job; every response is kept in `jobResponses`. Only the 
+    /// job's employer or agent owner update `get_agent_response`, so third parties cannot 
+    /// overwrite it. 
     pub fn append_response<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -225,6 +383,73 @@ where
             .original_result()
     }
 
+    pub fn simple_feedback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SimpleFeedbackRecord<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_simple_feedback")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Value of one smallest unit of `token_id` in the common reference unit, 18 decimals. 
+    /// Payments in unpriced tokens carry no weight. 
+    pub fn token_price<
+        Arg0: ProxyArg<TokenId<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_token_price")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn employer_reputation_score<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        employer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_employer_reputation_score")
+            .argument(&employer)
+            .original_result()
+    }
+
+    pub fn employer_total_jobs<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        employer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_employer_total_jobs")
+            .argument(&employer)
+            .original_result()
+    }
+
+    pub fn has_rated_employer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_rated_employer")
+            .argument(&job_id)
+            .original_result()
+    }
+
     pub fn validation_contract_address(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
@@ -256,6 +481,7 @@ where
             .original_result()
     }
 
+    /// Latest legacy `append_response` URI from the job's employer or agent owner. 
     pub fn agent_response<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -269,6 +495,20 @@ where
             .original_result()
     }
 
+    /// When set, `giveFeedback` only accepts feedback carrying a `FeedbackAuth`. 
+    pub fn feedback_auth_required<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isFeedbackAuthRequired")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
     pub fn last_feedback_index<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
@@ -285,6 +525,7 @@ where
             .original_result()
     }
 
+    /// Unbounded — prefer `getClientsPage` for agents with many clients. 
     pub fn feedback_clients<
         Arg0: ProxyArg<u64>,
     >(
@@ -323,6 +564,344 @@ where
             .argument(&address)
             .original_result()
     }
+
+    /// Scale for `giveFeedbackSimple` ratings. Existing scores are not rescaled, so this 
+    /// should be set before agents collect ratings. 
+    pub fn set_rating_scale<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        max_value: Arg0,
+        decimals: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_rating_scale")
+            .argument(&max_value)
+            .argument(&decimals)
+            .original_result()
+    }
+
+    /// Half-life of the decayed score. Applies from the next rating of each agent on. 
+    pub fn set_score_half_life<
+        Arg0: ProxyArg<DurationMillis>,
+    >(
+        self,
+        half_life: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_score_half_life")
+            .argument(&half_life)
+            .original_result()
+    }
+
+    /// How long after a `giveFeedbackSimple` rating the employer may still correct it. 
+    pub fn set_feedback_correction_window<
+        Arg0: ProxyArg<DurationMillis>,
+    >(
+        self,
+        window: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_feedback_correction_window")
+            .argument(&window)
+            .original_result()
+    }
+
+    /// Price of one smallest unit of `token_id` (18 decimals) used to weight ratings by 
+    /// job payment. A zero price removes the token from the table. 
+    pub fn set_token_price<
+        Arg0: ProxyArg<TokenId<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        price: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_token_price")
+            .argument(&token_id)
+            .argument(&price)
+            .original_result()
+    }
+
+    /// Scale that `giveFeedbackSimple` ratings and `get_reputation_score` are expressed in. 
+    pub fn get_rating_scale(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RatingScale> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_rating_scale")
+            .original_result()
+    }
+
+    pub fn get_client_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClientCount")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Cursor-based page over the addresses that gave ERC-8004 feedback to an agent, 
+    /// in first-feedback order. Returns the clients and the cursor to resume from. 
+    pub fn get_clients_page<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, ManagedVec<Env::Api, ManagedAddress<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClientsPage")
+            .argument(&agent_nonce)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Message the agent key signs for a `FeedbackAuth`. 
+    pub fn get_feedback_auth_message<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<TimestampMillis>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        index_limit: Arg2,
+        expiry: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_feedback_auth_message")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&index_limit)
+            .argument(&expiry)
+            .original_result()
+    }
+
+    pub fn get_response_count<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getResponseCount")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .original_result()
+    }
+
+    /// Cursor-based page over the responses to one feedback entry, oldest first. 
+    pub fn get_responses<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<usize>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+        from: Arg3,
+        size: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, ManagedVec<Env::Api, FeedbackResponse<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getResponses")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_job_response_count<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getJobResponseCount")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Cursor-based page over the legacy `append_response` responses to a job, oldest first. 
+    pub fn get_job_responses<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        job_id: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, ManagedVec<Env::Api, FeedbackResponse<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getJobResponses")
+            .argument(&job_id)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Rating average where each rating's weight halves every half-life, so recent jobs 
+    /// dominate. Same scale as `get_reputation_score`; 0 before the first rating. 
+    pub fn get_decayed_score<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_decayed_score")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Decayed number of ratings as of now (18 decimals): how much recent evidence backs 
+    /// `get_decayed_score`. 
+    pub fn get_decayed_rating_weight<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_decayed_rating_weight")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Rating average weighted by the value paid for each job (see `get_token_price`). 
+    /// Same scale as `get_reputation_score`; 0 until a paid, priced job is rated. 
+    pub fn get_payment_weighted_score<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_payment_weighted_score")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Total value behind `get_payment_weighted_score`, 18 decimals. 
+    pub fn get_payment_weight_total<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_payment_weight_total")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// `(count, sum, average)` of the agent's non-revoked ERC-8004 feedback under `tag`, 
+    /// values normalized to 18 decimals. 
+    pub fn get_score_by_tag<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        tag: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, BigInt<Env::Api>, BigInt<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_score_by_tag")
+            .argument(&agent_nonce)
+            .argument(&tag)
+            .original_result()
+    }
+
+    /// `(count, sum, average)` of `giveFeedbackSimple` ratings on the agent's jobs for 
+    /// `service_id`, on the rating scale. 
+    pub fn get_score_by_service<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_score_by_service")
+            .argument(&agent_nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    pub fn get_score_half_life(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationMillis> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_score_half_life")
+            .original_result()
+    }
+
+    /// Delivery metrics (time-to-proof, on-time ratio) maintained by the validation registry. 
+    pub fn get_agent_delivery_metrics<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::AgentDeliveryMetrics> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_delivery_metrics")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct FeedbackAuth<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub index_limit: u64,
+    pub expiry: TimestampMillis,
+    pub signature: ManagedBuffer<Api>,
 }
 
 #[type_abi]
@@ -338,6 +917,35 @@ where
     pub is_revoked: bool,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct FeedbackEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub client: ManagedAddress<Api>,
+    pub feedback_index: u64,
+    pub value: i64,
+    pub value_decimals: u8,
+    pub tag1: ManagedBuffer<Api>,
+    pub tag2: ManagedBuffer<Api>,
+    pub is_revoked: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct SimpleFeedbackRecord<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub agent_nonce: u64,
+    pub employer: ManagedAddress<Api>,
+    pub rating: BigUint<Api>,
+    pub timestamp: TimestampMillis,
+    pub payment_weight: BigUint<Api>,
+    pub service_id: Option<u32>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub struct NewFeedbackEventData<Api>
@@ -353,3 +961,31 @@ where
     pub feedback_uri: ManagedBuffer<Api>,
     pub feedback_hash: ManagedBuffer<Api>,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ResponseAppendedEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub response_uri: ManagedBuffer<Api>,
+    pub response_hash: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct RatingScale {
+    pub max_value: u64,
+    pub decimals: u8,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct FeedbackResponse<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub responder: ManagedAddress<Api>,
+    pub response_uri: ManagedBuffer<Api>,
+    pub response_hash: ManagedBuffer<Api>,
+}
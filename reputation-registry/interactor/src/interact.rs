@@ -154,7 +154,6 @@ impl ContractInteract {
 
     pub async fn give_feedback_simple(&mut self) {
         let job_id = ManagedBuffer::new_from_bytes(&b""[..]);
-        let agent_nonce = 0u64;
         let rating = BigUint::<StaticApi>::from(0u128);
 
         let response = self
@@ -164,7 +163,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(reputation_registry_proxy::ReputationRegistryProxy)
-            .give_feedback_simple(job_id, agent_nonce, rating)
+            .give_feedback_simple(job_id, rating)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -181,6 +180,8 @@ impl ContractInteract {
         let endpoint = ManagedBuffer::new_from_bytes(&b""[..]);
         let feedback_uri = ManagedBuffer::new_from_bytes(&b""[..]);
         let feedback_hash = ManagedBuffer::new_from_bytes(&b""[..]);
+        let feedback_auth =
+            OptionalValue::<reputation_registry_proxy::FeedbackAuth<StaticApi>>::None;
 
         let response = self
             .interactor
@@ -198,6 +199,7 @@ impl ContractInteract {
                 endpoint,
                 feedback_uri,
                 feedback_hash,
+                feedback_auth,
             )
             .returns(ReturnsResultUnmanaged)
             .run()
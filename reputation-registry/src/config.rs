@@ -1,5 +1,11 @@
 multiversx_sc::imports!();
 
+use crate::errors::{
+    ERR_INVALID_CORRECTION_WINDOW, ERR_INVALID_HALF_LIFE, ERR_INVALID_RATING_SCALE,
+    ERR_INVALID_VALUE_DECIMALS,
+};
+use crate::structs::RatingScale;
+
 #[multiversx_sc::module]
 pub trait ConfigModule:
     common::cross_contract::CrossContractModule + crate::storage::StorageModule
@@ -15,4 +21,48 @@ pub trait ConfigModule:
     fn set_validation_contract_address(&self, address: ManagedAddress) {
         self.validation_contract_address().set(&address);
     }
+
+    /// Scale for `giveFeedbackSimple` ratings. Existing scores are not rescaled, so this
+    /// should be set before agents collect ratings.
+    #[only_owner]
+    #[endpoint(set_rating_scale)]
+    fn set_rating_scale(&self, max_value: u64, decimals: u8) {
+        require!(max_value > 0, ERR_INVALID_RATING_SCALE);
+        require!(decimals <= 18, ERR_INVALID_VALUE_DECIMALS);
+        self.rating_scale().set(RatingScale {
+            max_value,
+            decimals,
+        });
+    }
+
+    /// Half-life of the decayed score. Applies from the next rating of each agent on.
+    #[only_owner]
+    #[endpoint(set_score_half_life)]
+    fn set_score_half_life(&self, half_life: DurationMillis) {
+        require!(half_life > DurationMillis::zero(), ERR_INVALID_HALF_LIFE);
+        self.score_half_life().set(half_life);
+    }
+
+    /// How long after a `giveFeedbackSimple` rating the employer may still correct it.
+    #[only_owner]
+    #[endpoint(set_feedback_correction_window)]
+    fn set_feedback_correction_window(&self, window: DurationMillis) {
+        require!(
+            window > DurationMillis::zero(),
+            ERR_INVALID_CORRECTION_WINDOW
+        );
+        self.feedback_correction_window().set(window);
+    }
+
+    /// Price of one smallest unit of `token_id` (18 decimals) used to weight ratings by
+    /// job payment. A zero price removes the token from the table.
+    #[only_owner]
+    #[endpoint(set_token_price)]
+    fn set_token_price(&self, token_id: TokenId, price: BigUint) {
+        if price == 0u64 {
+            self.token_price(&token_id).clear();
+        } else {
+            self.token_price(&token_id).set(price);
+        }
+    }
 }
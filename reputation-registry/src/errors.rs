@@ -1,9 +1,21 @@
-pub use common::errors::ERR_JOB_NOT_FOUND;
+pub use common::errors::{ERR_AGENT_NOT_FOUND, ERR_JOB_NOT_FOUND, ERR_NOT_AGENT_OWNER};
 
 pub const ERR_NOT_EMPLOYER: &str = "Only the employer can provide feedback";
+pub const ERR_JOB_NOT_VERIFIED: &str = "Feedback requires a verified job";
 pub const ERR_FEEDBACK_ALREADY_PROVIDED: &str = "Feedback already provided for this job";
 pub const ERR_AGENT_OWNER_CANNOT_SELF_REVIEW: &str =
     "Agent owner cannot give feedback to own agent";
 pub const ERR_INVALID_VALUE_DECIMALS: &str = "Value decimals must be 0-18";
+pub const ERR_INVALID_RATING_SCALE: &str = "Rating scale maximum must be greater than zero";
+pub const ERR_INVALID_HALF_LIFE: &str = "Half-life must be greater than zero";
+pub const ERR_INVALID_CORRECTION_WINDOW: &str = "Correction window must be greater than zero";
+pub const ERR_RATING_OUT_OF_RANGE: &str = "Rating is outside the rating scale";
 pub const ERR_FEEDBACK_NOT_FOUND: &str = "Feedback not found";
 pub const ERR_FEEDBACK_ALREADY_REVOKED: &str = "Feedback already revoked";
+pub const ERR_FEEDBACK_AUTH_REQUIRED: &str = "Agent requires a feedback authorization";
+pub const ERR_FEEDBACK_AUTH_EXPIRED: &str = "Feedback authorization has expired";
+pub const ERR_FEEDBACK_INDEX_LIMIT_EXCEEDED: &str = "Feedback index exceeds the authorized limit";
+pub const ERR_INVALID_PUBLIC_KEY: &str = "Agent public key is not a valid Ed25519 key";
+pub const ERR_CORRECTION_WINDOW_CLOSED: &str = "Feedback correction window has closed";
+pub const ERR_JOB_NOT_COMPLETED: &str = "Employer rating requires a completed job";
+pub const ERR_EMPLOYER_ALREADY_RATED: &str = "Employer already rated for this job";
@@ -15,11 +15,27 @@ pub struct NewFeedbackEventData<M: ManagedTypeApi> {
     pub feedback_hash: ManagedBuffer<M>,
 }
 
+/// ERC-8004 response event data.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ResponseAppendedEventData<M: ManagedTypeApi> {
+    pub response_uri: ManagedBuffer<M>,
+    pub response_hash: ManagedBuffer<M>,
+}
+
 #[multiversx_sc::module]
 pub trait EventsModule {
     #[event("reputationUpdated")]
     fn reputation_updated_event(&self, #[indexed] agent_nonce: u64, new_score: BigUint);
 
+    #[event("employerReputationUpdated")]
+    fn employer_reputation_updated_event(
+        &self,
+        #[indexed] employer: ManagedAddress,
+        #[indexed] job_id: ManagedBuffer,
+        new_score: BigUint,
+    );
+
     #[event("newFeedback")]
     fn new_feedback_event(
         &self,
@@ -42,5 +58,7 @@ pub trait EventsModule {
         #[indexed] agent_nonce: u64,
         #[indexed] client_address: ManagedAddress,
         #[indexed] feedback_index: u64,
+        #[indexed] responder: ManagedAddress,
+        data: ResponseAppendedEventData<Self::Api>,
     );
 }
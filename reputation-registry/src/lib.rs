@@ -9,10 +9,15 @@ mod events;
 pub mod storage;
 pub mod structs;
 mod utils;
+pub mod views;
 
+use common::pagination::page_range;
+use common::structs::JobStatus;
 use errors::*;
-use events::NewFeedbackEventData;
-use structs::FeedbackData;
+use events::{NewFeedbackEventData, ResponseAppendedEventData};
+use structs::{
+    FeedbackAuth, FeedbackData, FeedbackEntry, FeedbackKey, FeedbackResponse, SimpleFeedbackRecord,
+};
 
 #[multiversx_sc::contract]
 pub trait ReputationRegistry:
@@ -21,6 +26,7 @@ pub trait ReputationRegistry:
     + events::EventsModule
     + config::ConfigModule
     + utils::UtilsModule
+    + views::ViewsModule
 {
     #[init]
     fn init(
@@ -39,10 +45,10 @@ pub trait ReputationRegistry:
 
     // ── giveFeedbackSimple (MX-8004 original — on-chain scoring) ──
 
-    /// Simple feedback for a job. Caller must be the employer who created the job.
-    /// Computes a cumulative moving average on-chain.
+    /// Simple feedback for a verified job. Caller must be the employer who created the job;
+    /// the rated agent is the job's agent. Computes a cumulative moving average on-chain.
     #[endpoint(giveFeedbackSimple)]
-    fn give_feedback_simple(&self, job_id: ManagedBuffer, agent_nonce: u64, rating: BigUint) {
+    fn give_feedback_simple(&self, job_id: ManagedBuffer, rating: BigUint) {
         let caller = self.blockchain().get_caller();
         let validation_addr = self.validation_contract_address().get();
 
@@ -54,24 +60,136 @@ pub trait ReputationRegistry:
         // 2. Frontrunning Protection: Verify caller is the employer
         require!(caller == job_data.employer, ERR_NOT_EMPLOYER);
 
-        // 3. Duplicate Prevention
+        // 3. Completion: only a verified job earns a rating, and only for its own agent
+        require!(job_data.status == JobStatus::Verified, ERR_JOB_NOT_VERIFIED);
+        let agent_nonce = job_data.agent_nonce;
+
+        // 4. No self-dealing: an agent owner who hired their own agent cannot rate it
+        let identity_addr = self.identity_contract_address().get();
+        let agent_owner = self.external_agents(identity_addr).get_value(&agent_nonce);
+        require!(caller != agent_owner, ERR_AGENT_OWNER_CANNOT_SELF_REVIEW);
+
+        // 5. Rating must fit the configured scale
+        let scale = self.get_rating_scale_or_default();
+        require!(rating <= scale.max_value, ERR_RATING_OUT_OF_RANGE);
+
+        // 6. Duplicate Prevention
         require!(
             !self.has_given_feedback(job_id.clone()).get(),
             ERR_FEEDBACK_ALREADY_PROVIDED
         );
 
-        let new_score = self.calculate_new_score(agent_nonce, rating);
+        let payment_weight = self.job_payment_weight(&job_id);
+        let service_id = self.job_service_id(&job_id);
+        self.update_decayed_score(agent_nonce, &rating);
+        self.update_payment_weighted_score(agent_nonce, &payment_weight, &rating);
+        self.record_service_rating(agent_nonce, service_id, &rating);
+        let new_score = self.calculate_new_score(agent_nonce, rating.clone());
 
         self.reputation_score(agent_nonce).set(&new_score);
-        self.has_given_feedback(job_id).set(true);
+        self.has_given_feedback(job_id.clone()).set(true);
+        self.simple_feedback(&job_id).set(SimpleFeedbackRecord {
+            agent_nonce,
+            employer: caller,
+            rating,
+            timestamp: self.blockchain().get_block_timestamp_millis(),
+            payment_weight,
+            service_id,
+        });
 
         self.reputation_updated_event(agent_nonce, new_score);
     }
 
+    /// The employer can replace their `giveFeedbackSimple` rating until the correction
+    /// window after the original rating closes. Every score is updated as if the new
+    /// rating had been given in the first place.
+    #[endpoint(correctFeedbackSimple)]
+    fn correct_feedback_simple(&self, job_id: ManagedBuffer, new_rating: BigUint) {
+        let record_mapper = self.simple_feedback(&job_id);
+        require!(!record_mapper.is_empty(), ERR_FEEDBACK_NOT_FOUND);
+        let mut record = record_mapper.get();
+
+        require!(
+            self.blockchain().get_caller() == record.employer,
+            ERR_NOT_EMPLOYER
+        );
+        require!(
+            self.blockchain().get_block_timestamp_millis()
+                <= record.timestamp + self.get_feedback_correction_window_or_default(),
+            ERR_CORRECTION_WINDOW_CLOSED
+        );
+        let scale = self.get_rating_scale_or_default();
+        require!(new_rating <= scale.max_value, ERR_RATING_OUT_OF_RANGE);
+
+        let new_score = self.replace_rating(&record, &new_rating);
+        record.rating = new_rating;
+        let agent_nonce = record.agent_nonce;
+        record_mapper.set(record);
+
+        self.reputation_updated_event(agent_nonce, new_score);
+    }
+
+    // ── rateEmployer (agent side of the job) ──
+
+    /// The owner of the job's agent rates the employer once the job has an outcome
+    /// (verified, rejected, or cancelled after a proof was submitted). Same scale,
+    /// duplicate prevention and averaging as agent scores.
+    #[endpoint(rateEmployer)]
+    fn rate_employer(&self, job_id: ManagedBuffer, rating: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let validation_addr = self.validation_contract_address().get();
+
+        let job_mapper = self.external_job_data(validation_addr.clone(), &job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        let job_data = job_mapper.get();
+
+        let identity_addr = self.identity_contract_address().get();
+        let agent_owner = self
+            .external_agents(identity_addr)
+            .get_value(&job_data.agent_nonce);
+        require!(caller == agent_owner, ERR_NOT_AGENT_OWNER);
+        require!(
+            job_data.employer != agent_owner,
+            ERR_AGENT_OWNER_CANNOT_SELF_REVIEW
+        );
+
+        // A job cancelled after delivery still tells the agent how the employer behaved
+        let completed = match job_data.status {
+            JobStatus::Verified | JobStatus::Rejected => true,
+            JobStatus::Cancelled => !self
+                .external_job_proofs(validation_addr, &job_id)
+                .is_empty(),
+            _ => false,
+        };
+        require!(completed, ERR_JOB_NOT_COMPLETED);
+
+        let scale = self.get_rating_scale_or_default();
+        require!(rating <= scale.max_value, ERR_RATING_OUT_OF_RANGE);
+
+        let rated_mapper = self.has_rated_employer(&job_id);
+        require!(!rated_mapper.get(), ERR_EMPLOYER_ALREADY_RATED);
+        rated_mapper.set(true);
+
+        let employer = job_data.employer;
+        let rating_sum = self.employer_rating_sum(&employer).update(|sum| {
+            *sum += rating;
+            sum.clone()
+        });
+        let total_jobs = self.employer_total_jobs(&employer).update(|n| {
+            *n += 1;
+            *n
+        });
+        let new_score = rating_sum / total_jobs;
+        self.employer_reputation_score(&employer).set(&new_score);
+
+        self.employer_reputation_updated_event(employer, job_id, new_score);
+    }
+
     // ── giveFeedback (ERC-8004 compliant — raw signals) ──
 
     /// ERC-8004: Anyone can give feedback (except the agent owner).
     /// Stores raw signals — no on-chain scoring. Off-chain aggregation expected.
+    /// `feedback_auth` is verified whenever given and mandatory if the agent requires it.
     #[endpoint(giveFeedback)]
     fn give_feedback(
         &self,
@@ -83,6 +201,7 @@ pub trait ReputationRegistry:
         endpoint: ManagedBuffer,
         feedback_uri: ManagedBuffer,
         feedback_hash: ManagedBuffer,
+        feedback_auth: OptionalValue<FeedbackAuth<Self::Api>>,
     ) {
         let caller = self.blockchain().get_caller();
 
@@ -107,6 +226,7 @@ pub trait ReputationRegistry:
                 *idx += 1;
                 *idx
             });
+        self.verify_feedback_auth(agent_nonce, &caller, new_index, feedback_auth);
 
         // 4. Track client
         self.feedback_clients(agent_nonce).insert(caller.clone());
@@ -119,8 +239,18 @@ pub trait ReputationRegistry:
             tag2: tag2.clone(),
             is_revoked: false,
         };
+        let aggregated_start = self.aggregated_feedback_start(agent_nonce, &caller);
+        if aggregated_start.is_empty() {
+            aggregated_start.set(new_index);
+        }
+        self.update_tag_aggregates(agent_nonce, &data, true);
+        self.update_feedback_summary(agent_nonce, &caller, &data, true);
         self.feedback_data(agent_nonce, &caller, new_index)
             .set(data);
+        self.agent_feedback_log(agent_nonce).push(&FeedbackKey {
+            client: caller.clone(),
+            feedback_index: new_index,
+        });
 
         // 6. Emit event
         let event_data = NewFeedbackEventData {
@@ -136,6 +266,20 @@ pub trait ReputationRegistry:
         self.new_feedback_event(agent_nonce, caller, event_data);
     }
 
+    /// Agent owner only: require a `FeedbackAuth` on every `giveFeedback` for the agent.
+    #[endpoint(set_feedback_auth_required)]
+    fn set_feedback_auth_required(&self, agent_nonce: u64, required: bool) {
+        let identity_addr = self.identity_contract_address().get();
+        let agents_mapper = self.external_agents(identity_addr);
+        require!(agents_mapper.contains_id(&agent_nonce), ERR_AGENT_NOT_FOUND);
+        require!(
+            self.blockchain().get_caller() == agents_mapper.get_value(&agent_nonce),
+            ERR_NOT_AGENT_OWNER
+        );
+
+        self.feedback_auth_required(agent_nonce).set(required);
+    }
+
     // ── revokeFeedback (ERC-8004) ──
 
     /// ERC-8004: Only the original feedback author can revoke their feedback.
@@ -146,10 +290,14 @@ pub trait ReputationRegistry:
         let mapper = self.feedback_data(agent_nonce, &caller, feedback_index);
         require!(!mapper.is_empty(), ERR_FEEDBACK_NOT_FOUND);
 
-        mapper.update(|data| {
-            require!(!data.is_revoked, ERR_FEEDBACK_ALREADY_REVOKED);
-            data.is_revoked = true;
-        });
+        let mut data = mapper.get();
+        require!(!data.is_revoked, ERR_FEEDBACK_ALREADY_REVOKED);
+        data.is_revoked = true;
+        if self.is_feedback_aggregated(agent_nonce, &caller, feedback_index) {
+            self.update_tag_aggregates(agent_nonce, &data, false);
+            self.update_feedback_summary(agent_nonce, &caller, &data, false);
+        }
+        mapper.set(data);
 
         self.feedback_revoked_event(agent_nonce, caller, feedback_index);
     }
@@ -168,17 +316,172 @@ pub trait ReputationRegistry:
         mapper.get()
     }
 
+    // ── getSummary / readAllFeedback (ERC-8004 views) ──
+
+    /// ERC-8004 `getSummary`: number of non-revoked feedback entries for an agent and their
+    /// average value (`summary_value / 10^summary_value_decimals`), optionally filtered by
+    /// client set and tags. An empty tag or an empty client list means no filter. Clients
+    /// should be distinct. Backed by aggregates; cost grows only with the client list.
+    #[view(getSummary)]
+    fn get_summary(
+        &self,
+        agent_nonce: u64,
+        tag1: ManagedBuffer,
+        tag2: ManagedBuffer,
+        client_addresses: MultiValueEncoded<ManagedAddress>,
+    ) -> MultiValue3<u64, BigInt, u8> {
+        let mut count = 0u64;
+        let mut value_sum = BigInt::zero();
+
+        if client_addresses.is_empty() {
+            let aggregate = self.get_aggregate_or_default(&self.feedback_summary(
+                agent_nonce,
+                &ManagedAddress::zero(),
+                &tag1,
+                &tag2,
+            ));
+            count = aggregate.count;
+            value_sum = aggregate.sum;
+        } else {
+            for client in client_addresses {
+                let aggregate = self.get_aggregate_or_default(&self.feedback_summary(
+                    agent_nonce,
+                    &client,
+                    &tag1,
+                    &tag2,
+                ));
+                count += aggregate.count;
+                value_sum += aggregate.sum;
+            }
+        }
+
+        let average = if count == 0 {
+            BigInt::zero()
+        } else {
+            value_sum / BigInt::from(BigUint::from(count))
+        };
+        (count, average, 18u8).into()
+    }
+
+    #[view(getFeedbackCount)]
+    fn get_feedback_count(&self, agent_nonce: u64) -> usize {
+        self.agent_feedback_log(agent_nonce).len()
+    }
+
+    /// ERC-8004 `readAllFeedback`, paginated: scans at most `size` entries of the agent's
+    /// feedback log starting at `from` and returns the matching ones together with the
+    /// cursor to resume from; the scan is complete once the cursor equals
+    /// `getFeedbackCount`. Empty tags / client list = no filter.
+    #[view(readAllFeedback)]
+    fn read_all_feedback(
+        &self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+        tag1: ManagedBuffer,
+        tag2: ManagedBuffer,
+        include_revoked: bool,
+        client_addresses: MultiValueEncoded<ManagedAddress>,
+    ) -> MultiValue2<usize, ManagedVec<FeedbackEntry<Self::Api>>> {
+        let clients = client_addresses.to_vec();
+        let log = self.agent_feedback_log(agent_nonce);
+        let (start, end) = page_range(from, size, log.len());
+
+        let mut result = ManagedVec::new();
+        for index in start..end {
+            let key = log.get(index + 1);
+            if !clients.is_empty() && !clients.contains(&key.client) {
+                continue;
+            }
+
+            let data = self
+                .feedback_data(agent_nonce, &key.client, key.feedback_index)
+                .get();
+            if (!include_revoked && data.is_revoked)
+                || (!tag1.is_empty() && data.tag1 != tag1)
+                || (!tag2.is_empty() && data.tag2 != tag2)
+            {
+                continue;
+            }
+
+            result.push(FeedbackEntry {
+                client: key.client,
+                feedback_index: key.feedback_index,
+                value: data.value,
+                value_decimals: data.value_decimals,
+                tag1: data.tag1,
+                tag2: data.tag2,
+                is_revoked: data.is_revoked,
+            });
+        }
+        (end, result).into()
+    }
+
+    // ── appendResponse (ERC-8004) ──
+
+    /// ERC-8004: Anyone can append a response to a feedback entry (e.g., agent showing a
+    /// refund, data aggregator tagging feedback as spam). Responses are never overwritten.
+    #[endpoint(appendResponse)]
+    fn append_feedback_response(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+        response_uri: ManagedBuffer,
+        response_hash: ManagedBuffer,
+    ) {
+        require!(
+            !self
+                .feedback_data(agent_nonce, &client_address, feedback_index)
+                .is_empty(),
+            ERR_FEEDBACK_NOT_FOUND
+        );
+
+        let responder = self.blockchain().get_caller();
+        self.feedback_responses(agent_nonce, &client_address, feedback_index)
+            .push(&FeedbackResponse {
+                responder: responder.clone(),
+                response_uri: response_uri.clone(),
+                response_hash: response_hash.clone(),
+            });
+
+        self.response_appended_event(
+            agent_nonce,
+            client_address,
+            feedback_index,
+            responder,
+            ResponseAppendedEventData {
+                response_uri,
+                response_hash,
+            },
+        );
+    }
+
     // ── append_response (legacy, kept for backwards compat) ──
 
-    /// ERC-8004: Anyone can append a response to feedback (e.g., agent showing refund,
-    /// data aggregator tagging feedback as spam).
+    /// Anyone can respond to a job; every response is kept in `jobResponses`. Only the
+    /// job's employer or agent owner update `get_agent_response`, so third parties cannot
+    /// overwrite it.
     #[endpoint(append_response)]
     fn append_response(&self, job_id: ManagedBuffer, response_uri: ManagedBuffer) {
         let validation_addr = self.validation_contract_address().get();
         let job_mapper = self.external_job_data(validation_addr, &job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        let job_data = job_mapper.get();
 
-        // Per ERC-8004: anyone can append responses — no caller check
-        self.agent_response(job_id).set(response_uri);
+        let caller = self.blockchain().get_caller();
+        self.job_responses(&job_id).push(&FeedbackResponse {
+            responder: caller.clone(),
+            response_uri: response_uri.clone(),
+            response_hash: ManagedBuffer::new(),
+        });
+
+        let identity_addr = self.identity_contract_address().get();
+        let agent_owner = self
+            .external_agents(identity_addr)
+            .get_value(&job_data.agent_nonce);
+        if caller == job_data.employer || caller == agent_owner {
+            self.agent_response(job_id).set(response_uri);
+        }
     }
 }
@@ -1,7 +1,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::FeedbackData;
+use crate::structs::{
+    DecayedScore, FeedbackAggregate, FeedbackData, FeedbackKey, FeedbackResponse,
+    PaymentWeightedScore, RatingScale, ServiceAggregate, SimpleFeedbackRecord,
+};
 pub use common::structs::{JobData, JobStatus};
 
 #[multiversx_sc::module]
@@ -16,6 +19,68 @@ pub trait StorageModule: common::cross_contract::CrossContractModule {
     #[storage_mapper("totalJobs")]
     fn total_jobs(&self, agent_nonce: u64) -> SingleValueMapper<u64>;
 
+    /// Exact sum of the agent's current ratings; `reputationScore = ratingSum / totalJobs`.
+    #[storage_mapper("ratingSum")]
+    fn rating_sum(&self, agent_nonce: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(get_simple_feedback)]
+    #[storage_mapper("simpleFeedback")]
+    fn simple_feedback(
+        &self,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<SimpleFeedbackRecord<Self::Api>>;
+
+    /// Unset = `DEFAULT_FEEDBACK_CORRECTION_WINDOW`.
+    #[storage_mapper("feedbackCorrectionWindow")]
+    fn feedback_correction_window(&self) -> SingleValueMapper<DurationMillis>;
+
+    #[storage_mapper("decayedScore")]
+    fn decayed_score(&self, agent_nonce: u64) -> SingleValueMapper<DecayedScore<Self::Api>>;
+
+    #[storage_mapper("paymentWeightedScore")]
+    fn payment_weighted_score(
+        &self,
+        agent_nonce: u64,
+    ) -> SingleValueMapper<PaymentWeightedScore<Self::Api>>;
+
+    #[storage_mapper("serviceAggregate")]
+    fn service_aggregate(
+        &self,
+        agent_nonce: u64,
+        service_id: u32,
+    ) -> SingleValueMapper<ServiceAggregate<Self::Api>>;
+
+    /// Value of one smallest unit of `token_id` in the common reference unit, 18 decimals.
+    /// Payments in unpriced tokens carry no weight.
+    #[view(get_token_price)]
+    #[storage_mapper("tokenPrice")]
+    fn token_price(&self, token_id: &TokenId) -> SingleValueMapper<BigUint>;
+
+    /// Unset = `DEFAULT_SCORE_HALF_LIFE`.
+    #[storage_mapper("scoreHalfLife")]
+    fn score_half_life(&self) -> SingleValueMapper<DurationMillis>;
+
+    /// Unset = `DEFAULT_RATING_MAX` with `DEFAULT_RATING_DECIMALS`.
+    #[storage_mapper("ratingScale")]
+    fn rating_scale(&self) -> SingleValueMapper<RatingScale>;
+
+    // ── Employer reputation (rated by agent owners) ──
+
+    #[view(get_employer_reputation_score)]
+    #[storage_mapper("employerReputationScore")]
+    fn employer_reputation_score(&self, employer: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(get_employer_total_jobs)]
+    #[storage_mapper("employerTotalJobs")]
+    fn employer_total_jobs(&self, employer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("employerRatingSum")]
+    fn employer_rating_sum(&self, employer: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(has_rated_employer)]
+    #[storage_mapper("hasRatedEmployer")]
+    fn has_rated_employer(&self, job_id: &ManagedBuffer) -> SingleValueMapper<bool>;
+
     #[view(get_validation_contract_address)]
     #[storage_mapper("validationContractAddress")]
     fn validation_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -28,10 +93,15 @@ pub trait StorageModule: common::cross_contract::CrossContractModule {
     #[storage_mapper("hasGivenFeedback")]
     fn has_given_feedback(&self, job_id: ManagedBuffer) -> SingleValueMapper<bool>;
 
+    /// Latest legacy `append_response` URI from the job's employer or agent owner.
     #[view(get_agent_response)]
     #[storage_mapper("agentResponse")]
     fn agent_response(&self, job_id: ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
 
+    /// Every legacy `append_response` for a job, append-only.
+    #[storage_mapper("jobResponses")]
+    fn job_responses(&self, job_id: &ManagedBuffer) -> VecMapper<FeedbackResponse<Self::Api>>;
+
     // ── ERC-8004 feedback storage (giveFeedback — raw signals) ──
 
     #[storage_mapper("feedbackData")]
@@ -42,6 +112,11 @@ pub trait StorageModule: common::cross_contract::CrossContractModule {
         index: u64,
     ) -> SingleValueMapper<FeedbackData<Self::Api>>;
 
+    /// When set, `giveFeedback` only accepts feedback carrying a `FeedbackAuth`.
+    #[view(isFeedbackAuthRequired)]
+    #[storage_mapper("feedbackAuthRequired")]
+    fn feedback_auth_required(&self, agent_nonce: u64) -> SingleValueMapper<bool>;
+
     #[view(getLastIndex)]
     #[storage_mapper("lastFeedbackIndex")]
     fn last_feedback_index(
@@ -50,6 +125,48 @@ pub trait StorageModule: common::cross_contract::CrossContractModule {
         client: &ManagedAddress,
     ) -> SingleValueMapper<u64>;
 
+    /// Aggregate per tag; feedback counts under both `tag1` and `tag2` (once if equal).
+    #[storage_mapper("tagAggregate")]
+    fn tag_aggregate(
+        &self,
+        agent_nonce: u64,
+        tag: &ManagedBuffer,
+    ) -> SingleValueMapper<FeedbackAggregate<Self::Api>>;
+
+    /// ERC-8004 `getSummary` buckets per `(client, tag1, tag2)`. The zero address and the
+    /// empty tag act as "any client" / "any tag".
+    #[storage_mapper("feedbackSummary")]
+    fn feedback_summary(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        tag1: &ManagedBuffer,
+        tag2: &ManagedBuffer,
+    ) -> SingleValueMapper<FeedbackAggregate<Self::Api>>;
+
+    /// First feedback index of `client` for the agent that was added to `tagAggregate` and
+    /// `feedbackSummary`. Earlier indexes predate the aggregates and are never subtracted.
+    #[storage_mapper("aggregatedFeedbackStart")]
+    fn aggregated_feedback_start(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    /// Every ERC-8004 feedback given to an agent, in submission order.
+    #[storage_mapper("agentFeedbackLog")]
+    fn agent_feedback_log(&self, agent_nonce: u64) -> VecMapper<FeedbackKey<Self::Api>>;
+
+    /// ERC-8004 responses to one feedback entry, append-only.
+    #[storage_mapper("feedbackResponses")]
+    fn feedback_responses(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        feedback_index: u64,
+    ) -> VecMapper<FeedbackResponse<Self::Api>>;
+
+    /// Unbounded — prefer `getClientsPage` for agents with many clients.
     #[view(getClients)]
     #[storage_mapper("feedbackClients")]
     fn feedback_clients(&self, agent_nonce: u64) -> UnorderedSetMapper<ManagedAddress>;
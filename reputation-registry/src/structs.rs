@@ -11,3 +11,101 @@ pub struct FeedbackData<M: ManagedTypeApi> {
     pub tag2: ManagedBuffer<M>,
     pub is_revoked: bool,
 }
+
+/// ERC-8004 `feedbackAuth`: the agent lets one client give feedback up to `index_limit`
+/// until `expiry`. `signature` is the agent key's Ed25519 signature over
+/// `get_feedback_auth_message`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct FeedbackAuth<M: ManagedTypeApi> {
+    pub index_limit: u64,
+    pub expiry: TimestampMillis,
+    pub signature: ManagedBuffer<M>,
+}
+
+/// Scale for `giveFeedbackSimple` ratings: integers in `0..=max_value`, read as
+/// `rating / 10^decimals` (e.g. `max_value = 10000, decimals = 2` is 0.00-100.00).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct RatingScale {
+    pub max_value: u64,
+    pub decimals: u8,
+}
+
+/// A `giveFeedbackSimple` rating as recorded, so the employer can correct it within the
+/// correction window without depending on the (possibly cleaned up) job.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct SimpleFeedbackRecord<M: ManagedTypeApi> {
+    pub agent_nonce: u64,
+    pub employer: ManagedAddress<M>,
+    pub rating: BigUint<M>,
+    pub timestamp: TimestampMillis,
+    pub payment_weight: BigUint<M>,
+    pub service_id: Option<u32>,
+}
+
+/// Exponentially decayed rating average for an agent. Both sums are 18-decimal fixed point
+/// and are decayed to `last_update` only; the ratio is the decayed score.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct DecayedScore<M: ManagedTypeApi> {
+    pub weighted_sum: BigUint<M>,
+    pub total_weight: BigUint<M>,
+    pub last_update: TimestampMillis,
+}
+
+/// Rating average weighted by the value paid for each job (18-decimal weights).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct PaymentWeightedScore<M: ManagedTypeApi> {
+    pub weighted_sum: BigUint<M>,
+    pub total_weight: BigUint<M>,
+}
+
+/// Running total of ERC-8004 feedback values, normalized to 18 decimals. Revoked feedback
+/// is taken out again.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct FeedbackAggregate<M: ManagedTypeApi> {
+    pub count: u64,
+    pub sum: BigInt<M>,
+}
+
+/// Running total of `giveFeedbackSimple` ratings for jobs of one service (rating scale).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ServiceAggregate<M: ManagedTypeApi> {
+    pub count: u64,
+    pub sum: BigUint<M>,
+}
+
+/// Position of one ERC-8004 feedback in an agent's feedback log.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct FeedbackKey<M: ManagedTypeApi> {
+    pub client: ManagedAddress<M>,
+    pub feedback_index: u64,
+}
+
+/// ERC-8004 response to a feedback entry (or, via the legacy endpoint, to a job).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct FeedbackResponse<M: ManagedTypeApi> {
+    pub responder: ManagedAddress<M>,
+    pub response_uri: ManagedBuffer<M>,
+    pub response_hash: ManagedBuffer<M>,
+}
+
+/// One entry returned by `readAllFeedback`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct FeedbackEntry<M: ManagedTypeApi> {
+    pub client: ManagedAddress<M>,
+    pub feedback_index: u64,
+    pub value: i64,
+    pub value_decimals: u8,
+    pub tag1: ManagedBuffer<M>,
+    pub tag2: ManagedBuffer<M>,
+    pub is_revoked: bool,
+}
@@ -1,21 +1,421 @@
 multiversx_sc::imports!();
 
+use crate::errors::{
+    ERR_AGENT_NOT_FOUND, ERR_FEEDBACK_AUTH_EXPIRED, ERR_FEEDBACK_AUTH_REQUIRED,
+    ERR_FEEDBACK_INDEX_LIMIT_EXCEEDED, ERR_INVALID_PUBLIC_KEY,
+};
+use crate::structs::{
+    DecayedScore, FeedbackAggregate, FeedbackAuth, FeedbackData, PaymentWeightedScore, RatingScale,
+    ServiceAggregate, SimpleFeedbackRecord,
+};
+
+pub const DEFAULT_RATING_MAX: u64 = 100;
+pub const DEFAULT_RATING_DECIMALS: u8 = 0;
+pub const DEFAULT_SCORE_HALF_LIFE: DurationMillis = DurationMillis::new(30 * 24 * 60 * 60 * 1000);
+pub const DEFAULT_FEEDBACK_CORRECTION_WINDOW: DurationMillis =
+    DurationMillis::new(24 * 60 * 60 * 1000);
+
+/// Fixed-point precision of the decayed score sums (18 decimals).
+pub const WAD: u64 = 1_000_000_000_000_000_000;
+
 #[multiversx_sc::module]
 pub trait UtilsModule:
     common::cross_contract::CrossContractModule + crate::storage::StorageModule
 {
-    /// Cumulative moving average: new_score = (current * (n-1) + rating) / n
+    /// Average of all ratings: new_score = (rating_sum + rating) / n. The exact sum keeps
+    /// the average free of rounding drift and lets corrections replace a rating.
     fn calculate_new_score(&self, agent_nonce: u64, rating: BigUint) -> BigUint {
+        let rating_sum = self.get_rating_sum_or_seed(agent_nonce) + rating;
         let total_jobs = self.total_jobs(agent_nonce).update(|n| {
             *n += 1;
             *n
         });
 
-        let current_score = self.reputation_score(agent_nonce).get();
-        let total_big = BigUint::from(total_jobs);
-        let prev_total = &total_big - 1u32;
-        let weighted_score = current_score * prev_total;
+        self.rating_sum(agent_nonce).set(&rating_sum);
+        rating_sum / total_jobs
+    }
+
+    /// Agents rated before `ratingSum` existed start from their moving average.
+    fn get_rating_sum_or_seed(&self, agent_nonce: u64) -> BigUint {
+        let mapper = self.rating_sum(agent_nonce);
+        if mapper.is_empty() {
+            self.reputation_score(agent_nonce).get() * self.total_jobs(agent_nonce).get()
+        } else {
+            mapper.get()
+        }
+    }
+
+    /// Replaces `record.rating` with `new_rating` in every score it counts towards and
+    /// returns the new reputation score. All sums are exact except the decayed one, where
+    /// the old rating's weight is taken as its own decay since it was given.
+    fn replace_rating(
+        &self,
+        record: &SimpleFeedbackRecord<Self::Api>,
+        new_rating: &BigUint,
+    ) -> BigUint {
+        let agent_nonce = record.agent_nonce;
+        let old_rating = &record.rating;
+
+        let rating_sum = self.get_rating_sum_or_seed(agent_nonce) + new_rating - old_rating;
+        self.rating_sum(agent_nonce).set(&rating_sum);
+        let new_score = rating_sum / self.total_jobs(agent_nonce).get();
+        self.reputation_score(agent_nonce).set(&new_score);
+
+        let mut decayed = self.get_decayed_score_or_default(agent_nonce);
+        let factor = self.decay_factor(decayed.last_update - record.timestamp);
+        let old_part = old_rating * &factor;
+        decayed.weighted_sum += new_rating * &factor;
+        decayed.weighted_sum = if decayed.weighted_sum > old_part {
+            decayed.weighted_sum - old_part
+        } else {
+            BigUint::zero()
+        };
+        self.decayed_score(agent_nonce).set(decayed);
+
+        if record.payment_weight > 0u64 {
+            let mut score = self.get_payment_weighted_score_or_default(agent_nonce);
+            score.weighted_sum += new_rating * &record.payment_weight;
+            score.weighted_sum -= old_rating * &record.payment_weight;
+            self.payment_weighted_score(agent_nonce).set(score);
+        }
+
+        if let Some(service_id) = record.service_id {
+            let mut aggregate = self.get_service_aggregate_or_default(agent_nonce, service_id);
+            aggregate.sum += new_rating;
+            aggregate.sum -= old_rating;
+            self.service_aggregate(agent_nonce, service_id)
+                .set(aggregate);
+        }
+
+        new_score
+    }
+
+    /// Decays the agent's previous ratings to now, then adds `rating` with full weight.
+    fn update_decayed_score(&self, agent_nonce: u64, rating: &BigUint) {
+        let now = self.blockchain().get_block_timestamp_millis();
+        let mut score = self.get_decayed_score_or_default(agent_nonce);
+        let factor = self.decay_factor(now - score.last_update);
+        score.weighted_sum = &score.weighted_sum * &factor / WAD + rating * WAD;
+        score.total_weight = &score.total_weight * &factor / WAD + WAD;
+        score.last_update = now;
+        self.decayed_score(agent_nonce).set(score);
+    }
+
+    /// `0.5^(elapsed / half_life)` in 18-decimal fixed point. Whole half-lives halve
+    /// exactly; within a half-life the curve is interpolated linearly.
+    fn decay_factor(&self, elapsed: DurationMillis) -> BigUint {
+        let half_life = self.get_score_half_life_or_default().as_u64_millis();
+        let elapsed = elapsed.as_u64_millis();
+        let halvings = elapsed / half_life;
+        if halvings >= 64 {
+            return BigUint::zero();
+        }
+
+        let remainder = (elapsed % half_life) as u128;
+        let partial = WAD as u128 - WAD as u128 * remainder / (2 * half_life as u128);
+        BigUint::from(partial) >> halvings as usize
+    }
+
+    /// Adds `rating` weighted by the value of the job's payment. Unpaid jobs and payments
+    /// in unpriced tokens only count towards the unweighted scores.
+    fn update_payment_weighted_score(&self, agent_nonce: u64, weight: &BigUint, rating: &BigUint) {
+        if *weight == 0u64 {
+            return;
+        }
+
+        let mut score = self.get_payment_weighted_score_or_default(agent_nonce);
+        score.weighted_sum += rating * weight;
+        score.total_weight += weight;
+        self.payment_weighted_score(agent_nonce).set(score);
+    }
+
+    /// `amount * price` of the service price paid for the job, 18 decimals; 0 if unpaid
+    /// or unpriced.
+    fn job_payment_weight(&self, job_id: &ManagedBuffer) -> BigUint {
+        let validation_addr = self.validation_contract_address().get();
+        let payment_mapper = self.external_job_payment(validation_addr, job_id);
+        if payment_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let payment = payment_mapper.get();
+        let price_mapper = self.token_price(&payment.token_identifier);
+        if price_mapper.is_empty() {
+            return BigUint::zero();
+        }
+        payment.amount.as_big_uint() * &price_mapper.get()
+    }
+
+    /// The job's service ID, if it was created for a service.
+    fn job_service_id(&self, job_id: &ManagedBuffer) -> Option<u32> {
+        let validation_addr = self.validation_contract_address().get();
+        let service_mapper = self.external_job_service_id(validation_addr, job_id);
+        if service_mapper.is_empty() {
+            None
+        } else {
+            Some(service_mapper.get())
+        }
+    }
+
+    /// Adds `rating` to the aggregate of the job's service, if the job has one.
+    fn record_service_rating(&self, agent_nonce: u64, service_id: Option<u32>, rating: &BigUint) {
+        let Some(service_id) = service_id else {
+            return;
+        };
+
+        let mut aggregate = self.get_service_aggregate_or_default(agent_nonce, service_id);
+        aggregate.count += 1;
+        aggregate.sum += rating;
+        self.service_aggregate(agent_nonce, service_id)
+            .set(aggregate);
+    }
+
+    /// Adds (or, on revocation, removes) `data` under each of its non-empty tags.
+    fn update_tag_aggregates(&self, agent_nonce: u64, data: &FeedbackData<Self::Api>, add: bool) {
+        let value = self.normalize_feedback_value(data.value, data.value_decimals);
+        let mut tags = ManagedVec::<Self::Api, ManagedBuffer>::new();
+        if !data.tag1.is_empty() {
+            tags.push(data.tag1.clone());
+        }
+        if !data.tag2.is_empty() && data.tag2 != data.tag1 {
+            tags.push(data.tag2.clone());
+        }
+
+        for tag in tags.iter() {
+            self.update_summary_bucket(&self.tag_aggregate(agent_nonce, &tag), &value, add);
+        }
+    }
+
+    /// Whether the feedback was counted in the aggregates: feedback given before they
+    /// existed (i.e. before the contract upgrade) was not.
+    fn is_feedback_aggregated(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        feedback_index: u64,
+    ) -> bool {
+        let start_mapper = self.aggregated_feedback_start(agent_nonce, client);
+        !start_mapper.is_empty() && feedback_index >= start_mapper.get()
+    }
+
+    /// Add (or, when `add` is false, remove) one feedback from every `getSummary` bucket
+    /// it belongs to: each combination of `client` / any client, `tag1` / any tag1 and
+    /// `tag2` / any tag2.
+    fn update_feedback_summary(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        data: &FeedbackData<Self::Api>,
+        add: bool,
+    ) {
+        let value = self.normalize_feedback_value(data.value, data.value_decimals);
+        let any_client = ManagedAddress::zero();
+        let any_tag = ManagedBuffer::new();
+
+        let tag1_buckets: &[&ManagedBuffer] = if data.tag1.is_empty() {
+            &[&any_tag]
+        } else {
+            &[&data.tag1, &any_tag]
+        };
+        let tag2_buckets: &[&ManagedBuffer] = if data.tag2.is_empty() {
+            &[&any_tag]
+        } else {
+            &[&data.tag2, &any_tag]
+        };
+
+        for bucket_client in [client, &any_client] {
+            for bucket_tag1 in tag1_buckets {
+                for bucket_tag2 in tag2_buckets {
+                    let mapper =
+                        self.feedback_summary(agent_nonce, bucket_client, bucket_tag1, bucket_tag2);
+                    self.update_summary_bucket(&mapper, &value, add);
+                }
+            }
+        }
+    }
+
+    fn update_summary_bucket(
+        &self,
+        mapper: &SingleValueMapper<FeedbackAggregate<Self::Api>>,
+        value: &BigInt,
+        add: bool,
+    ) {
+        let mut aggregate = self.get_aggregate_or_default(mapper);
+        if add {
+            aggregate.count += 1;
+            aggregate.sum += value;
+        } else {
+            aggregate.count -= 1;
+            aggregate.sum -= value;
+        }
+
+        if aggregate.count == 0 {
+            mapper.clear();
+        } else {
+            mapper.set(aggregate);
+        }
+    }
+
+    /// Checks the authorization for `client`'s feedback number `feedback_index`. Without
+    /// one, feedback is only accepted if the agent does not require it.
+    fn verify_feedback_auth(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        feedback_index: u64,
+        feedback_auth: OptionalValue<FeedbackAuth<Self::Api>>,
+    ) {
+        let auth = match feedback_auth {
+            OptionalValue::Some(auth) => auth,
+            OptionalValue::None => {
+                require!(
+                    !self.feedback_auth_required(agent_nonce).get(),
+                    ERR_FEEDBACK_AUTH_REQUIRED
+                );
+                return;
+            }
+        };
+
+        require!(
+            self.blockchain().get_block_timestamp_millis() <= auth.expiry,
+            ERR_FEEDBACK_AUTH_EXPIRED
+        );
+        require!(
+            feedback_index <= auth.index_limit,
+            ERR_FEEDBACK_INDEX_LIMIT_EXCEEDED
+        );
+
+        let identity_addr = self.identity_contract_address().get();
+        require!(
+            self.external_agents(identity_addr.clone())
+                .contains_id(&agent_nonce),
+            ERR_AGENT_NOT_FOUND
+        );
+        let public_key = self
+            .external_agent_details(identity_addr, agent_nonce)
+            .get()
+            .public_key;
+        let agent_key = ManagedAddress::try_from(public_key)
+            .unwrap_or_else(|_| sc_panic!(ERR_INVALID_PUBLIC_KEY));
+
+        let message =
+            self.compute_feedback_auth_message(agent_nonce, client, auth.index_limit, auth.expiry);
+        self.crypto()
+            .verify_ed25519(agent_key.as_managed_buffer(), &message, &auth.signature);
+    }
+
+    /// `sha256(contract || agent_nonce || client || index_limit || expiry)`, integers
+    /// big-endian, signed by the agent for a `FeedbackAuth`.
+    fn compute_feedback_auth_message(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        index_limit: u64,
+        expiry: TimestampMillis,
+    ) -> ManagedBuffer {
+        let mut payload = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        payload.append_bytes(&agent_nonce.to_be_bytes());
+        payload.append(client.as_managed_buffer());
+        payload.append_bytes(&index_limit.to_be_bytes());
+        payload.append_bytes(&expiry.as_u64_millis().to_be_bytes());
+        self.crypto().sha256(&payload).as_managed_buffer().clone()
+    }
+
+    /// `value / 10^decimals` as an 18-decimal fixed-point number.
+    fn normalize_feedback_value(&self, value: i64, value_decimals: u8) -> BigInt {
+        let scale = BigUint::from(10u64).pow(18 - value_decimals as u32);
+        BigInt::from(value) * BigInt::from(scale)
+    }
+
+    fn get_aggregate_or_default(
+        &self,
+        mapper: &SingleValueMapper<FeedbackAggregate<Self::Api>>,
+    ) -> FeedbackAggregate<Self::Api> {
+        if mapper.is_empty() {
+            FeedbackAggregate {
+                count: 0,
+                sum: BigInt::zero(),
+            }
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_service_aggregate_or_default(
+        &self,
+        agent_nonce: u64,
+        service_id: u32,
+    ) -> ServiceAggregate<Self::Api> {
+        let mapper = self.service_aggregate(agent_nonce, service_id);
+        if mapper.is_empty() {
+            ServiceAggregate {
+                count: 0,
+                sum: BigUint::zero(),
+            }
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_payment_weighted_score_or_default(
+        &self,
+        agent_nonce: u64,
+    ) -> PaymentWeightedScore<Self::Api> {
+        let mapper = self.payment_weighted_score(agent_nonce);
+        if mapper.is_empty() {
+            PaymentWeightedScore {
+                weighted_sum: BigUint::zero(),
+                total_weight: BigUint::zero(),
+            }
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_decayed_score_or_default(&self, agent_nonce: u64) -> DecayedScore<Self::Api> {
+        let mapper = self.decayed_score(agent_nonce);
+        if mapper.is_empty() {
+            DecayedScore {
+                weighted_sum: BigUint::zero(),
+                total_weight: BigUint::zero(),
+                last_update: TimestampMillis::zero(),
+            }
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_score_half_life_or_default(&self) -> DurationMillis {
+        let mapper = self.score_half_life();
+        if mapper.is_empty() {
+            DEFAULT_SCORE_HALF_LIFE
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_feedback_correction_window_or_default(&self) -> DurationMillis {
+        let mapper = self.feedback_correction_window();
+        if mapper.is_empty() {
+            DEFAULT_FEEDBACK_CORRECTION_WINDOW
+        } else {
+            mapper.get()
+        }
+    }
 
-        (weighted_score + rating) / total_big
+    fn get_rating_scale_or_default(&self) -> RatingScale {
+        let mapper = self.rating_scale();
+        if mapper.is_empty() {
+            RatingScale {
+                max_value: DEFAULT_RATING_MAX,
+                decimals: DEFAULT_RATING_DECIMALS,
+            }
+        } else {
+            mapper.get()
+        }
     }
 }
@@ -0,0 +1,204 @@
+multiversx_sc::imports!();
+
+use common::pagination::page_range;
+use common::structs::AgentDeliveryMetrics;
+
+use crate::structs::{FeedbackResponse, RatingScale};
+use crate::utils::WAD;
+
+#[multiversx_sc::module]
+pub trait ViewsModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::StorageModule
+    + crate::utils::UtilsModule
+{
+    /// Scale that `giveFeedbackSimple` ratings and `get_reputation_score` are expressed in.
+    #[view(get_rating_scale)]
+    fn get_rating_scale(&self) -> RatingScale {
+        self.get_rating_scale_or_default()
+    }
+
+    #[view(getClientCount)]
+    fn get_client_count(&self, agent_nonce: u64) -> usize {
+        self.feedback_clients(agent_nonce).len()
+    }
+
+    /// Cursor-based page over the addresses that gave ERC-8004 feedback to an agent,
+    /// in first-feedback order. Returns the clients and the cursor to resume from.
+    #[view(getClientsPage)]
+    fn get_clients_page(
+        &self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+    ) -> MultiValue2<usize, ManagedVec<ManagedAddress>> {
+        let mapper = self.feedback_clients(agent_nonce);
+        let (start, end) = page_range(from, size, mapper.len());
+
+        let mut result = ManagedVec::new();
+        for index in start..end {
+            result.push(mapper.get_by_index(index + 1));
+        }
+        (end, result).into()
+    }
+
+    /// Message the agent key signs for a `FeedbackAuth`.
+    #[view(get_feedback_auth_message)]
+    fn get_feedback_auth_message(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        index_limit: u64,
+        expiry: TimestampMillis,
+    ) -> ManagedBuffer {
+        self.compute_feedback_auth_message(agent_nonce, &client_address, index_limit, expiry)
+    }
+
+    #[view(getResponseCount)]
+    fn get_response_count(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+    ) -> usize {
+        self.feedback_responses(agent_nonce, &client_address, feedback_index)
+            .len()
+    }
+
+    /// Cursor-based page over the responses to one feedback entry, oldest first.
+    #[view(getResponses)]
+    fn get_responses(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+        from: usize,
+        size: usize,
+    ) -> MultiValue2<usize, ManagedVec<FeedbackResponse<Self::Api>>> {
+        self.responses_page(
+            &self.feedback_responses(agent_nonce, &client_address, feedback_index),
+            from,
+            size,
+        )
+    }
+
+    #[view(getJobResponseCount)]
+    fn get_job_response_count(&self, job_id: ManagedBuffer) -> usize {
+        self.job_responses(&job_id).len()
+    }
+
+    /// Cursor-based page over the legacy `append_response` responses to a job, oldest first.
+    #[view(getJobResponses)]
+    fn get_job_responses(
+        &self,
+        job_id: ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> MultiValue2<usize, ManagedVec<FeedbackResponse<Self::Api>>> {
+        self.responses_page(&self.job_responses(&job_id), from, size)
+    }
+
+    fn responses_page(
+        &self,
+        mapper: &VecMapper<FeedbackResponse<Self::Api>>,
+        from: usize,
+        size: usize,
+    ) -> MultiValue2<usize, ManagedVec<FeedbackResponse<Self::Api>>> {
+        let (start, end) = page_range(from, size, mapper.len());
+
+        let mut result = ManagedVec::new();
+        for index in start..end {
+            result.push(mapper.get(index + 1));
+        }
+        (end, result).into()
+    }
+
+    /// Rating average where each rating's weight halves every half-life, so recent jobs
+    /// dominate. Same scale as `get_reputation_score`; 0 before the first rating.
+    #[view(get_decayed_score)]
+    fn get_decayed_score(&self, agent_nonce: u64) -> BigUint {
+        let score = self.get_decayed_score_or_default(agent_nonce);
+        if score.total_weight == 0u64 {
+            return BigUint::zero();
+        }
+        score.weighted_sum / score.total_weight
+    }
+
+    /// Decayed number of ratings as of now (18 decimals): how much recent evidence backs
+    /// `get_decayed_score`.
+    #[view(get_decayed_rating_weight)]
+    fn get_decayed_rating_weight(&self, agent_nonce: u64) -> BigUint {
+        let score = self.get_decayed_score_or_default(agent_nonce);
+        let now = self.blockchain().get_block_timestamp_millis();
+        score.total_weight * self.decay_factor(now - score.last_update) / WAD
+    }
+
+    /// Rating average weighted by the value paid for each job (see `get_token_price`).
+    /// Same scale as `get_reputation_score`; 0 until a paid, priced job is rated.
+    #[view(get_payment_weighted_score)]
+    fn get_payment_weighted_score(&self, agent_nonce: u64) -> BigUint {
+        let score = self.get_payment_weighted_score_or_default(agent_nonce);
+        if score.total_weight == 0u64 {
+            return BigUint::zero();
+        }
+        score.weighted_sum / score.total_weight
+    }
+
+    /// Total value behind `get_payment_weighted_score`, 18 decimals.
+    #[view(get_payment_weight_total)]
+    fn get_payment_weight_total(&self, agent_nonce: u64) -> BigUint {
+        self.get_payment_weighted_score_or_default(agent_nonce)
+            .total_weight
+    }
+
+    /// `(count, sum, average)` of the agent's non-revoked ERC-8004 feedback under `tag`,
+    /// values normalized to 18 decimals.
+    #[view(get_score_by_tag)]
+    fn get_score_by_tag(
+        &self,
+        agent_nonce: u64,
+        tag: ManagedBuffer,
+    ) -> MultiValue3<u64, BigInt, BigInt> {
+        let aggregate = self.get_aggregate_or_default(&self.tag_aggregate(agent_nonce, &tag));
+        let average = if aggregate.count == 0 {
+            BigInt::zero()
+        } else {
+            &aggregate.sum / &BigInt::from(BigUint::from(aggregate.count))
+        };
+        (aggregate.count, aggregate.sum, average).into()
+    }
+
+    /// `(count, sum, average)` of `giveFeedbackSimple` ratings on the agent's jobs for
+    /// `service_id`, on the rating scale.
+    #[view(get_score_by_service)]
+    fn get_score_by_service(
+        &self,
+        agent_nonce: u64,
+        service_id: u32,
+    ) -> MultiValue3<u64, BigUint, BigUint> {
+        let aggregate = self.get_service_aggregate_or_default(agent_nonce, service_id);
+        let average = if aggregate.count == 0 {
+            BigUint::zero()
+        } else {
+            &aggregate.sum / aggregate.count
+        };
+        (aggregate.count, aggregate.sum, average).into()
+    }
+
+    #[view(get_score_half_life)]
+    fn get_score_half_life(&self) -> DurationMillis {
+        self.get_score_half_life_or_default()
+    }
+
+    /// Delivery metrics (time-to-proof, on-time ratio) maintained by the validation registry.
+    #[view(get_agent_delivery_metrics)]
+    fn get_agent_delivery_metrics(&self, agent_nonce: u64) -> AgentDeliveryMetrics {
+        let validation_addr = self.validation_contract_address().get();
+        let mapper = self.external_agent_delivery_metrics(validation_addr, agent_nonce);
+        if mapper.is_empty() {
+            AgentDeliveryMetrics::default()
+        } else {
+            mapper.get()
+        }
+    }
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           15
+// Endpoints:                           48
 // Async Callback (empty):               1
-// Total number of exported functions:  18
+// Total number of exported functions:  51
 
 #![no_std]
 
@@ -21,20 +21,53 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         giveFeedbackSimple => give_feedback_simple
+        correctFeedbackSimple => correct_feedback_simple
+        rateEmployer => rate_employer
         giveFeedback => give_feedback
+        set_feedback_auth_required => set_feedback_auth_required
         revokeFeedback => revoke_feedback
         readFeedback => read_feedback
+        getSummary => get_summary
+        getFeedbackCount => get_feedback_count
+        readAllFeedback => read_all_feedback
+        appendResponse => append_feedback_response
         append_response => append_response
         get_reputation_score => reputation_score
         get_total_jobs => total_jobs
+        get_simple_feedback => simple_feedback
+        get_token_price => token_price
+        get_employer_reputation_score => employer_reputation_score
+        get_employer_total_jobs => employer_total_jobs
+        has_rated_employer => has_rated_employer
         get_validation_contract_address => validation_contract_address
         get_identity_contract_address => identity_contract_address
         has_given_feedback => has_given_feedback
         get_agent_response => agent_response
+        isFeedbackAuthRequired => feedback_auth_required
         getLastIndex => last_feedback_index
         getClients => feedback_clients
         set_identity_contract_address => set_identity_contract_address
         set_validation_contract_address => set_validation_contract_address
+        set_rating_scale => set_rating_scale
+        set_score_half_life => set_score_half_life
+        set_feedback_correction_window => set_feedback_correction_window
+        set_token_price => set_token_price
+        get_rating_scale => get_rating_scale
+        getClientCount => get_client_count
+        getClientsPage => get_clients_page
+        get_feedback_auth_message => get_feedback_auth_message
+        getResponseCount => get_response_count
+        getResponses => get_responses
+        getJobResponseCount => get_job_response_count
+        getJobResponses => get_job_responses
+        get_decayed_score => get_decayed_score
+        get_decayed_rating_weight => get_decayed_rating_weight
+        get_payment_weighted_score => get_payment_weighted_score
+        get_payment_weight_total => get_payment_weight_total
+        get_score_by_tag => get_score_by_tag
+        get_score_by_service => get_score_by_service
+        get_score_half_life => get_score_half_life
+        get_agent_delivery_metrics => get_agent_delivery_metrics
     )
 }
 
@@ -9,6 +9,9 @@ pub const WORKER: TestAddress = TestAddress::new("worker");
 pub const VALIDATOR: TestAddress = TestAddress::new("validator");
 pub const EMPLOYER: TestAddress = TestAddress::new("employer");
 pub const AGENT: TestAddress = TestAddress::new("agent");
+pub const ARBITER_1: TestAddress = TestAddress::new("arbiter_1");
+pub const ARBITER_2: TestAddress = TestAddress::new("arbiter_2");
+pub const ARBITER_3: TestAddress = TestAddress::new("arbiter_3");
 
 // ── SC Addresses ──
 pub const IDENTITY_SC_ADDRESS: TestSCAddress = TestSCAddress::new("identity-registry");
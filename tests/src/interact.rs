@@ -380,13 +380,7 @@ impl CsInteract {
     // ── Reputation Registry ──
 
 
-    pub async fn give_feedback_simple(
-        &mut self,
-        from: &Address,
-        job_id: &[u8],
-        agent_nonce: u64,
-        rating: u64,
-    ) {
+    pub async fn give_feedback_simple(&mut self, from: &Address, job_id: &[u8], rating: u64) {
         self.interactor
             .tx()
             .from(from)
@@ -395,7 +389,6 @@ impl CsInteract {
             .typed(ReputationRegistryProxy)
             .give_feedback_simple(
                 ManagedBuffer::<StaticApi>::from(job_id),
-                agent_nonce,
                 BigUint::<StaticApi>::from(rating),
             )
             .returns(ReturnsResultUnmanaged)
@@ -1,22 +1,31 @@
 use crate::constants::*;
-use common::structs::{AgentDetails, JobData, MetadataEntry, ServiceConfigInput};
+use common::structs::{
+    AgentDeliveryMetrics, AgentDetails, JobData, JobStatus, JobTimeline, MetadataEntry,
+    ProofRecord, ServiceConfigInput, ValidationRequestData, ValidationResponseRecord,
+};
+use ed25519_dalek::{Signer, SigningKey};
 use identity_registry::storage::StorageModule;
+use multiversx_sc::contract_base::ContractBase;
 use multiversx_sc::proxy_imports::MultiValue2;
 use multiversx_sc::proxy_imports::OptionalValue;
 use multiversx_sc::types::{
-    BigUint, EgldOrEsdtTokenPayment, EsdtTokenIdentifier, ManagedAddress, ManagedArgBuffer,
-    ManagedBuffer, MultiValueEncoded, ReturnsNewManagedAddress, ReturnsResult, TestEsdtTransfer,
-    TokenId,
+    BigInt, BigUint, DurationMillis, EgldOrEsdtTokenPayment, EsdtTokenIdentifier, EsdtTokenPayment,
+    ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedVec, MultiValueEncoded, Payment,
+    ReturnsNewManagedAddress, ReturnsResult, TestEsdtTransfer, TimestampMillis, TokenId,
 };
 use multiversx_sc_scenario::{
     ScenarioTxRun, ScenarioTxWhitebox, ScenarioWorld, api::StaticApi, imports::ExpectMessage,
 };
 use proxies::{
     identity_registry_proxy::IdentityRegistryProxy,
-    reputation_registry_proxy::ReputationRegistryProxy,
-    validation_registry_proxy::ValidationRegistryProxy,
+    reputation_registry_proxy::{
+        FeedbackAuth, FeedbackEntry, FeedbackResponse, RatingScale, ReputationRegistryProxy,
+    },
+    validation_registry_proxy::{DisputeData, JobInput, ProofInput, ValidationRegistryProxy},
 };
+use reputation_registry::storage::StorageModule as ReputationStorageModule;
 use validation_registry::storage::ExternalStorageModule;
+use validation_registry::utils::UtilsModule;
 
 pub fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -99,6 +108,9 @@ impl AgentTestState {
         world.account(WORKER).nonce(1).balance(1_000_000u64);
         world.account(VALIDATOR).nonce(1).balance(1_000_000u64);
         world.account(AGENT).nonce(1).balance(1_000_000u64);
+        for arbiter in [ARBITER_1, ARBITER_2, ARBITER_3] {
+            world.account(arbiter).nonce(1).balance(1_000_000u64);
+        }
 
         Self {
             world,
@@ -304,6 +316,40 @@ impl AgentTestState {
             .run();
     }
 
+    pub fn set_service_sla(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        service_id: u32,
+        sla_ms: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_service_sla(nonce, service_id, DurationMillis::new(sla_ms))
+            .run();
+    }
+
+    pub fn set_service_sla_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        service_id: u32,
+        sla_ms: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_service_sla(nonce, service_id, DurationMillis::new(sla_ms))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
     pub fn remove_service_configs(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -345,6 +391,90 @@ impl AgentTestState {
             .run();
     }
 
+    pub fn init_job_with_requirements(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        requirements_hash: &[u8],
+        requirements_uri: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job_with_requirements(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                ManagedBuffer::from(requirements_hash),
+                ManagedBuffer::from(requirements_uri),
+                OptionalValue::<u32>::None,
+            )
+            .run();
+    }
+
+    pub fn create_job(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+    ) -> Vec<u8> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .create_job(agent_nonce, OptionalValue::<u32>::None)
+            .returns(ReturnsResult)
+            .run()
+            .to_vec()
+    }
+
+    pub fn create_job_with_requirements(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        requirements_hash: &[u8],
+        requirements_uri: &[u8],
+    ) -> Vec<u8> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .create_job_with_requirements(
+                agent_nonce,
+                ManagedBuffer::from(requirements_hash),
+                ManagedBuffer::from(requirements_uri),
+                OptionalValue::<u32>::None,
+            )
+            .returns(ReturnsResult)
+            .run()
+            .to_vec()
+    }
+
+    /// Whitebox helper: the job ID `create_job` would generate for `employer` with `nonce`
+    /// in the current block.
+    pub fn compute_job_id(
+        &mut self,
+        employer: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+    ) -> Vec<u8> {
+        let mut job_id = Vec::new();
+        let employer = employer.to_address();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .whitebox(validation_registry::contract_obj, |sc| {
+                let block_nonce = sc.blockchain().get_block_nonce();
+                job_id = sc
+                    .compute_job_id(&ManagedAddress::from(&employer), nonce, block_nonce)
+                    .to_vec();
+            });
+        job_id
+    }
+
     pub fn init_job_with_payment(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -403,50 +533,64 @@ impl AgentTestState {
             .run();
     }
 
-    pub fn submit_proof(
+    pub fn init_job_held(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
-        proof: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        amount: u64,
     ) {
         self.world
             .tx()
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .init_job_held(ManagedBuffer::from(job_id), agent_nonce, service_id)
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
             .run();
     }
 
-    pub fn submit_proof_with_nft(
+    pub fn init_job_held_with_requirements(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
-        proof: &[u8],
-        token_id: &multiversx_sc::types::TestTokenIdentifier,
-        nonce: u64,
+        agent_nonce: u64,
+        requirements_hash: &[u8],
+        requirements_uri: &[u8],
+        service_id: u32,
+        amount: u64,
     ) {
         self.world
             .tx()
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .submit_proof_with_nft(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
-            .single_esdt(
-                &EsdtTokenIdentifier::from(*token_id),
-                nonce,
-                &BigUint::from(1u64),
+            .init_job_held_with_requirements(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                ManagedBuffer::from(requirements_hash),
+                ManagedBuffer::from(requirements_uri),
+                service_id,
             )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
             .run();
     }
 
-    pub fn submit_proof_with_nft_expect_err(
+    pub fn cancel_job(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .cancel_job(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn cancel_job_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
-        proof: &[u8],
-        token_id: &multiversx_sc::types::TestTokenIdentifier,
-        nonce: u64,
         err_msg: &str,
     ) {
         self.world
@@ -454,45 +598,138 @@ impl AgentTestState {
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .submit_proof_with_nft(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
-            .single_esdt(
-                &EsdtTokenIdentifier::from(*token_id),
-                nonce,
-                &BigUint::from(1u64),
-            )
+            .cancel_job(ManagedBuffer::from(job_id))
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn validation_request(
+    pub fn claim_refund(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .claim_refund(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn claim_refund_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
-        validator: &multiversx_sc::types::TestAddress,
-        request_uri: &[u8],
-        request_hash: &[u8],
+        err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .validation_request(
+            .claim_refund(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn accept_delivery(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .accept_delivery(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn accept_delivery_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .accept_delivery(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn challenge_delivery(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .challenge_delivery(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn challenge_delivery_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .challenge_delivery(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn finalize_job(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .finalize_job(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn finalize_job_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .finalize_job(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn enable_optimistic_verification(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        challenge_window_ms: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .enable_optimistic_verification(
                 ManagedBuffer::from(job_id),
-                validator.to_managed_address(),
-                ManagedBuffer::from(request_uri),
-                ManagedBuffer::from(request_hash),
+                DurationMillis::new(challenge_window_ms),
             )
             .run();
     }
 
-    pub fn validation_request_expect_err(
+    pub fn enable_optimistic_verification_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
-        validator: &multiversx_sc::types::TestAddress,
-        request_uri: &[u8],
-        request_hash: &[u8],
+        challenge_window_ms: u64,
         err_msg: &str,
     ) {
         self.world
@@ -500,48 +737,34 @@ impl AgentTestState {
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .validation_request(
+            .enable_optimistic_verification(
                 ManagedBuffer::from(job_id),
-                validator.to_managed_address(),
-                ManagedBuffer::from(request_uri),
-                ManagedBuffer::from(request_hash),
+                DurationMillis::new(challenge_window_ms),
             )
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn validation_response(
+    pub fn approve_validator(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        request_hash: &[u8],
-        response: u8,
-        response_uri: &[u8],
-        response_hash: &[u8],
-        tag: &[u8],
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
     ) {
         self.world
             .tx()
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .validation_response(
-                ManagedBuffer::from(request_hash),
-                response,
-                ManagedBuffer::from(response_uri),
-                ManagedBuffer::from(response_hash),
-                ManagedBuffer::from(tag),
-            )
+            .approve_validator(ManagedBuffer::from(job_id), validator.to_managed_address())
             .run();
     }
 
-    pub fn validation_response_expect_err(
+    pub fn approve_validator_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        request_hash: &[u8],
-        response: u8,
-        response_uri: &[u8],
-        response_hash: &[u8],
-        tag: &[u8],
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
         err_msg: &str,
     ) {
         self.world
@@ -549,203 +772,1702 @@ impl AgentTestState {
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .validation_response(
-                ManagedBuffer::from(request_hash),
-                response,
-                ManagedBuffer::from(response_uri),
-                ManagedBuffer::from(response_hash),
-                ManagedBuffer::from(tag),
-            )
+            .approve_validator(ManagedBuffer::from(job_id), validator.to_managed_address())
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn clean_old_jobs(&mut self, job_ids: Vec<&[u8]>) {
-        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+    pub fn add_arbiters(&mut self, arbiters: Vec<&multiversx_sc::types::TestAddress>) {
+        let mut encoded = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        for arbiter in arbiters {
+            encoded.push(arbiter.to_managed_address());
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .add_arbiters(encoded)
+            .run();
+    }
+
+    pub fn set_dispute_quorum(&mut self, quorum: u32) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_dispute_quorum(quorum)
+            .run();
+    }
+
+    pub fn set_dispute_bond(&mut self, amount: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_dispute_bond(
+                TokenId::from(PAYMENT_TOKEN.as_bytes()),
+                BigUint::from(amount),
+            )
+            .run();
+    }
+
+    pub fn open_dispute(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        reason_uri: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .open_dispute(ManagedBuffer::from(job_id), ManagedBuffer::from(reason_uri))
+            .run();
+    }
+
+    pub fn open_dispute_with_bond(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        reason_uri: &[u8],
+        bond: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .open_dispute(ManagedBuffer::from(job_id), ManagedBuffer::from(reason_uri))
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, bond))
+            .run();
+    }
+
+    pub fn open_dispute_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        reason_uri: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .open_dispute(ManagedBuffer::from(job_id), ManagedBuffer::from(reason_uri))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn vote_dispute(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        for_agent: bool,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .vote_dispute(ManagedBuffer::from(job_id), for_agent)
+            .run();
+    }
+
+    pub fn vote_dispute_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        for_agent: bool,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .vote_dispute(ManagedBuffer::from(job_id), for_agent)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn expire_dispute(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .expire_dispute(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn expire_dispute_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .expire_dispute(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_dispute_period(&mut self, period_ms: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_dispute_period(DurationMillis::new(period_ms))
+            .run();
+    }
+
+    pub fn remove_arbiters(&mut self, arbiters: Vec<&multiversx_sc::types::TestAddress>) {
+        let mut encoded = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        for arbiter in arbiters {
+            encoded.push(arbiter.to_managed_address());
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .remove_arbiters(encoded)
+            .run();
+    }
+
+    fn job_inputs(
+        jobs: &[(&[u8], u64, Option<u32>)],
+    ) -> MultiValueEncoded<StaticApi, JobInput<StaticApi>> {
+        let mut encoded = MultiValueEncoded::new();
+        for (job_id, agent_nonce, service_id) in jobs {
+            encoded.push(JobInput {
+                job_id: ManagedBuffer::from(*job_id),
+                agent_nonce: *agent_nonce,
+                service_id: *service_id,
+                requirements_hash: ManagedBuffer::new(),
+                requirements_uri: ManagedBuffer::new(),
+            });
+        }
+        encoded
+    }
+
+    fn payment_token_transfers(
+        amounts: &[u64],
+    ) -> ManagedVec<StaticApi, EsdtTokenPayment<StaticApi>> {
+        let mut payments = ManagedVec::new();
+        for amount in amounts {
+            payments.push(EsdtTokenPayment::new(
+                PAYMENT_TOKEN.to_token_identifier(),
+                0,
+                BigUint::from(*amount),
+            ));
+        }
+        payments
+    }
+
+    /// `jobs` = `(job_id, agent_nonce, service_id)`; `payments` are PAYMENT_TOKEN amounts.
+    pub fn init_jobs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        jobs: Vec<(&[u8], u64, Option<u32>)>,
+        payments: Vec<u64>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_jobs(Self::job_inputs(&jobs))
+            .payment(Self::payment_token_transfers(&payments))
+            .run();
+    }
+
+    pub fn init_jobs_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        jobs: Vec<(&[u8], u64, Option<u32>)>,
+        payments: Vec<u64>,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_jobs(Self::job_inputs(&jobs))
+            .payment(Self::payment_token_transfers(&payments))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    fn proof_inputs(
+        proofs: &[(&[u8], &[u8])],
+    ) -> MultiValueEncoded<StaticApi, ProofInput<StaticApi>> {
+        let mut encoded = MultiValueEncoded::new();
+        for (job_id, proof_hash) in proofs {
+            encoded.push(ProofInput {
+                job_id: ManagedBuffer::from(*job_id),
+                proof_hash: ManagedBuffer::from(*proof_hash),
+                uri: ManagedBuffer::new(),
+                content_type: ManagedBuffer::new(),
+            });
+        }
+        encoded
+    }
+
+    /// `proofs` = `(job_id, proof_hash)`
+    pub fn submit_proofs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        proofs: Vec<(&[u8], &[u8])>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proofs(Self::proof_inputs(&proofs))
+            .run();
+    }
+
+    pub fn submit_proofs_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        proofs: Vec<(&[u8], &[u8])>,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proofs(Self::proof_inputs(&proofs))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn submit_proof(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .run();
+    }
+
+    pub fn submit_proof_with_details(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof_hash: &[u8],
+        uri: &[u8],
+        content_type: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof_with_details(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(proof_hash),
+                ManagedBuffer::from(uri),
+                ManagedBuffer::from(content_type),
+            )
+            .run();
+    }
+
+    pub fn submit_proof_signed(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+        signature: &[u8],
+        expiry: TimestampMillis,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof_signed(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(proof),
+                ManagedBuffer::from(signature),
+                expiry,
+            )
+            .run();
+    }
+
+    pub fn submit_proof_signed_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+        signature: &[u8],
+        expiry: TimestampMillis,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof_signed(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(proof),
+                ManagedBuffer::from(signature),
+                expiry,
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    /// Signs the message returned by `get_proof_signing_message` with `key`.
+    pub fn sign_proof(
+        &mut self,
+        key: &SigningKey,
+        job_id: &[u8],
+        proof: &[u8],
+        expiry: TimestampMillis,
+    ) -> Vec<u8> {
+        let message = self
+            .world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_proof_signing_message(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(proof),
+                expiry,
+            )
+            .returns(ReturnsResult)
+            .run();
+        key.sign(&message.to_vec()).to_bytes().to_vec()
+    }
+
+    pub fn submit_proof_with_nft(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+        token_id: &multiversx_sc::types::TestTokenIdentifier,
+        nonce: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof_with_nft(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .single_esdt(
+                &EsdtTokenIdentifier::from(*token_id),
+                nonce,
+                &BigUint::from(1u64),
+            )
+            .run();
+    }
+
+    pub fn submit_proof_with_nft_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+        token_id: &multiversx_sc::types::TestTokenIdentifier,
+        nonce: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof_with_nft(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .single_esdt(
+                &EsdtTokenIdentifier::from(*token_id),
+                nonce,
+                &BigUint::from(1u64),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn validation_request(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_uri: &[u8],
+        request_hash: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_uri),
+                ManagedBuffer::from(request_hash),
+            )
+            .run();
+    }
+
+    pub fn validation_request_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_uri: &[u8],
+        request_hash: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_uri),
+                ManagedBuffer::from(request_hash),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn validation_response(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        response: u8,
+        response_uri: &[u8],
+        response_hash: &[u8],
+        tag: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_response(
+                ManagedBuffer::from(request_hash),
+                response,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+                ManagedBuffer::from(tag),
+            )
+            .run();
+    }
+
+    pub fn validation_response_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        response: u8,
+        response_uri: &[u8],
+        response_hash: &[u8],
+        tag: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_response(
+                ManagedBuffer::from(request_hash),
+                response,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+                ManagedBuffer::from(tag),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn clean_old_jobs(&mut self, job_ids: Vec<&[u8]>) {
+        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
         for id in &job_ids {
             ids_encoded.push(ManagedBuffer::from(*id));
         }
         self.world
-            .tx()
-            .from(OWNER_ADDRESS)
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .clean_old_jobs(ids_encoded)
+            .run();
+    }
+
+    pub fn set_job_retention(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        status: JobStatus,
+        retention_ms: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_job_retention(status, DurationMillis::new(retention_ms))
+            .run();
+    }
+
+    pub fn set_job_retention_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        status: JobStatus,
+        retention_ms: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_job_retention(status, DurationMillis::new(retention_ms))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_validation_reputation_address(&mut self, address: ManagedAddress<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_reputation_contract_address(address)
+            .run();
+    }
+
+    pub fn set_refund_timeout(&mut self, timeout_ms: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_refund_timeout(DurationMillis::new(timeout_ms))
+            .run();
+    }
+
+    pub fn set_feedback_window(&mut self, window_ms: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_feedback_window(DurationMillis::new(window_ms))
+            .run();
+    }
+
+    pub fn set_feedback_window_expect_err(&mut self, window_ms: u64, err_msg: &str) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_feedback_window(DurationMillis::new(window_ms))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_held_payment(&mut self, job_id: &[u8]) -> Option<Payment<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_held_payment(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn query_job_retention(&mut self, status: JobStatus) -> DurationMillis {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_retention(status)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    // ── Reputation Registry ──
+
+    pub fn give_feedback_simple(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        rating: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback_simple(ManagedBuffer::from(job_id), BigUint::from(rating))
+            .run();
+    }
+
+    pub fn give_feedback_simple_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        rating: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback_simple(ManagedBuffer::from(job_id), BigUint::from(rating))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn rate_employer(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        rating: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .rate_employer(ManagedBuffer::from(job_id), BigUint::from(rating))
+            .run();
+    }
+
+    pub fn rate_employer_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        rating: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .rate_employer(ManagedBuffer::from(job_id), BigUint::from(rating))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn correct_feedback_simple(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        new_rating: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .correct_feedback_simple(ManagedBuffer::from(job_id), BigUint::from(new_rating))
+            .run();
+    }
+
+    pub fn correct_feedback_simple_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        new_rating: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .correct_feedback_simple(ManagedBuffer::from(job_id), BigUint::from(new_rating))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_feedback_correction_window(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        window_ms: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_feedback_correction_window(DurationMillis::new(window_ms))
+            .run();
+    }
+
+    pub fn set_feedback_correction_window_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        window_ms: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_feedback_correction_window(DurationMillis::new(window_ms))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn revoke_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        feedback_index: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .revoke_feedback(agent_nonce, feedback_index)
+            .run();
+    }
+
+    pub fn set_rating_scale(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        max_value: u64,
+        decimals: u8,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_rating_scale(max_value, decimals)
+            .run();
+    }
+
+    pub fn set_rating_scale_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        max_value: u64,
+        decimals: u8,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_rating_scale(max_value, decimals)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_score_half_life(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        half_life_ms: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_score_half_life(DurationMillis::new(half_life_ms))
+            .run();
+    }
+
+    pub fn set_score_half_life_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        half_life_ms: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_score_half_life(DurationMillis::new(half_life_ms))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_token_price(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        token: &multiversx_sc::types::TestTokenIdentifier,
+        price: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_token_price(TokenId::from(token.as_bytes()), BigUint::from(price))
+            .run();
+    }
+
+    pub fn give_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+        value_decimals: u8,
+        tag1: &[u8],
+        tag2: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback(
+                agent_nonce,
+                value,
+                value_decimals,
+                ManagedBuffer::from(tag1),
+                ManagedBuffer::from(tag2),
+                ManagedBuffer::from(b"https://agent.example.com/api"),
+                ManagedBuffer::from(b"https://feedback.uri"),
+                ManagedBuffer::from(b"feedback_hash"),
+                OptionalValue::<FeedbackAuth<StaticApi>>::None,
+            )
+            .run();
+    }
+
+    pub fn give_feedback_with_auth(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+        auth: Option<FeedbackAuth<StaticApi>>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback(
+                agent_nonce,
+                value,
+                0u8,
+                ManagedBuffer::from(b"quality"),
+                ManagedBuffer::new(),
+                ManagedBuffer::from(b"https://agent.example.com/api"),
+                ManagedBuffer::from(b"https://feedback.uri"),
+                ManagedBuffer::from(b"feedback_hash"),
+                OptionalValue::from(auth),
+            )
+            .run();
+    }
+
+    pub fn give_feedback_with_auth_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+        auth: Option<FeedbackAuth<StaticApi>>,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback(
+                agent_nonce,
+                value,
+                0u8,
+                ManagedBuffer::from(b"quality"),
+                ManagedBuffer::new(),
+                ManagedBuffer::from(b"https://agent.example.com/api"),
+                ManagedBuffer::from(b"https://feedback.uri"),
+                ManagedBuffer::from(b"feedback_hash"),
+                OptionalValue::from(auth),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    /// Signs a `FeedbackAuth` for `client` with `key`.
+    pub fn sign_feedback_auth(
+        &mut self,
+        key: &SigningKey,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        index_limit: u64,
+        expiry: TimestampMillis,
+    ) -> FeedbackAuth<StaticApi> {
+        let message = self
+            .world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_feedback_auth_message(
+                agent_nonce,
+                client.to_managed_address(),
+                index_limit,
+                expiry,
+            )
+            .returns(ReturnsResult)
+            .run();
+        FeedbackAuth {
+            index_limit,
+            expiry,
+            signature: ManagedBuffer::from(key.sign(&message.to_vec()).to_bytes().as_slice()),
+        }
+    }
+
+    pub fn set_feedback_auth_required(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        required: bool,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_feedback_auth_required(agent_nonce, required)
+            .run();
+    }
+
+    pub fn set_feedback_auth_required_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        required: bool,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_feedback_auth_required(agent_nonce, required)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn append_response(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        response_uri: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .append_response(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(response_uri),
+            )
+            .run();
+    }
+
+    pub fn append_feedback_response(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+        response_uri: &[u8],
+        response_hash: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .append_feedback_response(
+                agent_nonce,
+                client.to_managed_address(),
+                feedback_index,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+            )
+            .run();
+    }
+
+    // ── Queries ──
+
+    pub fn query_agent_details(&mut self, nonce: u64) -> AgentDetails<StaticApi> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .agent_details(nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_agent_owner(&mut self, nonce: u64) -> ManagedAddress<StaticApi> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_agent_owner(nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_metadata(
+        &mut self,
+        nonce: u64,
+        key: &[u8],
+    ) -> OptionalValue<ManagedBuffer<StaticApi>> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_metadata(nonce, ManagedBuffer::from(key))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_service_config(
+        &mut self,
+        nonce: u64,
+        service_id: u32,
+    ) -> OptionalValue<EgldOrEsdtTokenPayment<StaticApi>> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_agent_service_config(nonce, service_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_is_job_verified(&mut self, job_id: &[u8]) -> bool {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .is_job_verified(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_job_data(&mut self, job_id: &[u8]) -> OptionalValue<JobData<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_data(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_job_proof_count(&mut self, job_id: &[u8]) -> usize {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_proof_count(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_job_proofs(
+        &mut self,
+        job_id: &[u8],
+        from: usize,
+        size: usize,
+    ) -> Vec<ProofRecord<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_proofs(ManagedBuffer::from(job_id), from, size)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn query_agent_proof_nonce(&mut self, agent_nonce: u64) -> u64 {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .agent_proof_nonce(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// `(requirements_hash, requirements_uri)`
+    pub fn query_job_requirements(
+        &mut self,
+        job_id: &[u8],
+    ) -> (ManagedBuffer<StaticApi>, ManagedBuffer<StaticApi>) {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_requirements(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    pub fn query_job_timeline(&mut self, job_id: &[u8]) -> JobTimeline {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_timeline(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// `(delivered_jobs, average_delivery_ms, on_time_ratio_bps)`
+    pub fn query_agent_delivery_stats(&mut self, agent_nonce: u64) -> (u64, u64, u64) {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_agent_delivery_stats(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    /// Delivery metrics as seen by the reputation registry (cross-contract read).
+    pub fn query_reputation_delivery_metrics(&mut self, agent_nonce: u64) -> AgentDeliveryMetrics {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_agent_delivery_metrics(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_dispute(&mut self, job_id: &[u8]) -> Option<DisputeData<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_dispute(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn query_validation_status(
+        &mut self,
+        request_hash: &[u8],
+    ) -> OptionalValue<ValidationRequestData<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_status(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_validation_response_count(&mut self, request_hash: &[u8]) -> usize {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_response_count(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_latest_validation_response(
+        &mut self,
+        request_hash: &[u8],
+    ) -> Option<ValidationResponseRecord<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_latest_validation_response(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn query_validation_responses(
+        &mut self,
+        request_hash: &[u8],
+        from: usize,
+        size: usize,
+    ) -> Vec<ValidationResponseRecord<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_responses(ManagedBuffer::from(request_hash), from, size)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn query_validation_summary(
+        &mut self,
+        agent_nonce: u64,
+        tag: &[u8],
+        validators: Vec<&multiversx_sc::types::TestAddress>,
+    ) -> (u64, u8) {
+        let mut validators_encoded =
+            MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        for validator in validators {
+            validators_encoded.push(validator.to_managed_address());
+        }
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_summary(agent_nonce, ManagedBuffer::from(tag), validators_encoded)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    pub fn query_agent_validations_page(
+        &mut self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+        validator: Option<&multiversx_sc::types::TestAddress>,
+        tag: Option<&[u8]>,
+        responded: Option<bool>,
+    ) -> (usize, Vec<ManagedBuffer<StaticApi>>) {
+        let (cursor, hashes) = self
+            .world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_agent_validations_page(
+                agent_nonce,
+                from,
+                size,
+                OptionalValue::Some(
+                    validator.map_or_else(ManagedAddress::zero, |v| v.to_managed_address()),
+                ),
+                OptionalValue::Some(ManagedBuffer::from(tag.unwrap_or_default())),
+                OptionalValue::from(responded),
+            )
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        (cursor, hashes.into_iter().collect())
+    }
+
+    pub fn query_agent_jobs_page(
+        &mut self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+        status: Option<JobStatus>,
+    ) -> (usize, Vec<ManagedBuffer<StaticApi>>) {
+        let (cursor, job_ids) = self
+            .world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_agent_jobs_page(agent_nonce, from, size, OptionalValue::from(status))
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        (cursor, job_ids.into_iter().collect())
+    }
+
+    pub fn query_employer_jobs_page(
+        &mut self,
+        employer: &multiversx_sc::types::TestAddress,
+        from: usize,
+        size: usize,
+        status: Option<JobStatus>,
+    ) -> (usize, Vec<ManagedBuffer<StaticApi>>) {
+        let (cursor, job_ids) = self
+            .world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_employer_jobs_page(
+                employer.to_managed_address(),
+                from,
+                size,
+                OptionalValue::from(status),
+            )
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        (cursor, job_ids.into_iter().collect())
+    }
+
+    pub fn query_clients_page(
+        &mut self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+    ) -> (usize, Vec<ManagedAddress<StaticApi>>) {
+        let (cursor, clients) = self
+            .world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_clients_page(agent_nonce, from, size)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        (cursor, clients.into_iter().collect())
+    }
+
+    pub fn query_reputation_score(&mut self, agent_nonce: u64) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .reputation_score(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_rating_scale(&mut self) -> RatingScale {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_rating_scale()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_decayed_score(&mut self, agent_nonce: u64) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_decayed_score(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// 18-decimal fixed point.
+    pub fn query_decayed_rating_weight(&mut self, agent_nonce: u64) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_decayed_rating_weight(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_score_half_life(&mut self) -> DurationMillis {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_score_half_life()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_payment_weighted_score(&mut self, agent_nonce: u64) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_payment_weighted_score(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// 18-decimal fixed point.
+    pub fn query_payment_weight_total(&mut self, agent_nonce: u64) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_payment_weight_total(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_job_payment(&mut self, job_id: &[u8]) -> Option<Payment<StaticApi>> {
+        self.world
+            .query()
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .clean_old_jobs(ids_encoded)
-            .run();
+            .get_job_payment(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
     }
 
-    // ── Reputation Registry ──
-
-    pub fn give_feedback_simple(
+    /// `(count, sum, average)`, 18 decimals.
+    pub fn query_score_by_tag(
         &mut self,
-        from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
         agent_nonce: u64,
-        rating: u64,
-    ) {
+        tag: &[u8],
+    ) -> (u64, BigInt<StaticApi>, BigInt<StaticApi>) {
         self.world
-            .tx()
-            .from(*from)
+            .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .give_feedback_simple(
-                ManagedBuffer::from(job_id),
-                agent_nonce,
-                BigUint::from(rating),
-            )
-            .run();
+            .get_score_by_tag(agent_nonce, ManagedBuffer::from(tag))
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
     }
 
-    pub fn give_feedback_simple_expect_err(
+    /// `(count, sum, average)` on the rating scale.
+    pub fn query_score_by_service(
         &mut self,
-        from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
         agent_nonce: u64,
-        rating: u64,
-        err_msg: &str,
-    ) {
+        service_id: u32,
+    ) -> (u64, BigUint<StaticApi>, BigUint<StaticApi>) {
         self.world
-            .tx()
-            .from(*from)
+            .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .give_feedback_simple(
-                ManagedBuffer::from(job_id),
-                agent_nonce,
-                BigUint::from(rating),
-            )
-            .returns(ExpectMessage(err_msg))
-            .run();
+            .get_score_by_service(agent_nonce, service_id)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
     }
 
-    pub fn append_response(
+    /// `(count, summary_value, summary_value_decimals)` from `getSummary`.
+    pub fn query_summary(
         &mut self,
-        from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        response_uri: &[u8],
-    ) {
+        agent_nonce: u64,
+        tag1: &[u8],
+        tag2: &[u8],
+        clients: &[&multiversx_sc::types::TestAddress],
+    ) -> (u64, BigInt<StaticApi>, u8) {
+        let mut client_addresses = MultiValueEncoded::new();
+        for client in clients {
+            client_addresses.push(client.to_managed_address());
+        }
         self.world
-            .tx()
-            .from(*from)
+            .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .append_response(
-                ManagedBuffer::from(job_id),
-                ManagedBuffer::from(response_uri),
+            .get_summary(
+                agent_nonce,
+                ManagedBuffer::from(tag1),
+                ManagedBuffer::from(tag2),
+                client_addresses,
             )
-            .run();
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
     }
 
-    // ── Queries ──
-
-    pub fn query_agent_details(&mut self, nonce: u64) -> AgentDetails<StaticApi> {
+    pub fn query_feedback_count(&mut self, agent_nonce: u64) -> usize {
         self.world
             .query()
-            .to(IDENTITY_SC_ADDRESS)
-            .typed(IdentityRegistryProxy)
-            .agent_details(nonce)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_feedback_count(agent_nonce)
             .returns(ReturnsResult)
             .run()
     }
 
-    pub fn query_agent_owner(&mut self, nonce: u64) -> ManagedAddress<StaticApi> {
+    /// `(next_cursor, entries)` from `readAllFeedback`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn query_all_feedback(
+        &mut self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+        tag1: &[u8],
+        tag2: &[u8],
+        include_revoked: bool,
+        clients: &[&multiversx_sc::types::TestAddress],
+    ) -> (usize, ManagedVec<StaticApi, FeedbackEntry<StaticApi>>) {
+        let mut client_addresses = MultiValueEncoded::new();
+        for client in clients {
+            client_addresses.push(client.to_managed_address());
+        }
         self.world
             .query()
-            .to(IDENTITY_SC_ADDRESS)
-            .typed(IdentityRegistryProxy)
-            .get_agent_owner(nonce)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .read_all_feedback(
+                agent_nonce,
+                from,
+                size,
+                ManagedBuffer::from(tag1),
+                ManagedBuffer::from(tag2),
+                include_revoked,
+                client_addresses,
+            )
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
-    pub fn query_metadata(
+    pub fn query_employer_reputation_score(
         &mut self,
-        nonce: u64,
-        key: &[u8],
-    ) -> OptionalValue<ManagedBuffer<StaticApi>> {
+        employer: &multiversx_sc::types::TestAddress,
+    ) -> BigUint<StaticApi> {
         self.world
             .query()
-            .to(IDENTITY_SC_ADDRESS)
-            .typed(IdentityRegistryProxy)
-            .get_metadata(nonce, ManagedBuffer::from(key))
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .employer_reputation_score(employer.to_managed_address())
             .returns(ReturnsResult)
             .run()
     }
 
-    pub fn query_service_config(
+    pub fn query_employer_total_jobs(
         &mut self,
-        nonce: u64,
-        service_id: u32,
-    ) -> OptionalValue<EgldOrEsdtTokenPayment<StaticApi>> {
+        employer: &multiversx_sc::types::TestAddress,
+    ) -> u64 {
         self.world
             .query()
-            .to(IDENTITY_SC_ADDRESS)
-            .typed(IdentityRegistryProxy)
-            .get_agent_service_config(nonce, service_id)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .employer_total_jobs(employer.to_managed_address())
             .returns(ReturnsResult)
             .run()
     }
 
-    pub fn query_is_job_verified(&mut self, job_id: &[u8]) -> bool {
+    pub fn query_has_rated_employer(&mut self, job_id: &[u8]) -> bool {
         self.world
             .query()
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .is_job_verified(ManagedBuffer::from(job_id))
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .has_rated_employer(ManagedBuffer::from(job_id))
             .returns(ReturnsResult)
             .run()
     }
 
-    pub fn query_job_data(&mut self, job_id: &[u8]) -> OptionalValue<JobData<StaticApi>> {
+    pub fn query_total_jobs(&mut self, agent_nonce: u64) -> u64 {
         self.world
             .query()
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .get_job_data(ManagedBuffer::from(job_id))
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .total_jobs(agent_nonce)
             .returns(ReturnsResult)
             .run()
     }
 
-    pub fn query_reputation_score(&mut self, agent_nonce: u64) -> BigUint<StaticApi> {
+    pub fn query_has_given_feedback(&mut self, job_id: &[u8]) -> bool {
         self.world
             .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .reputation_score(agent_nonce)
+            .has_given_feedback(ManagedBuffer::from(job_id))
             .returns(ReturnsResult)
             .run()
     }
 
-    pub fn query_total_jobs(&mut self, agent_nonce: u64) -> u64 {
+    // query_is_feedback_authorized removed — ERC-8004 compliance
+
+    pub fn query_agent_response(&mut self, job_id: &[u8]) -> ManagedBuffer<StaticApi> {
         self.world
             .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .total_jobs(agent_nonce)
+            .agent_response(ManagedBuffer::from(job_id))
             .returns(ReturnsResult)
             .run()
     }
 
-    pub fn query_has_given_feedback(&mut self, job_id: &[u8]) -> bool {
+    pub fn query_feedback_auth_required(&mut self, agent_nonce: u64) -> bool {
         self.world
             .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .has_given_feedback(ManagedBuffer::from(job_id))
+            .feedback_auth_required(agent_nonce)
             .returns(ReturnsResult)
             .run()
     }
 
-    // query_is_feedback_authorized removed — ERC-8004 compliance
+    pub fn query_response_count(
+        &mut self,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+    ) -> usize {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_response_count(agent_nonce, client.to_managed_address(), feedback_index)
+            .returns(ReturnsResult)
+            .run()
+    }
 
-    pub fn query_agent_response(&mut self, job_id: &[u8]) -> ManagedBuffer<StaticApi> {
+    /// `(next_cursor, responses)` from `getResponses`.
+    pub fn query_responses(
+        &mut self,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+        from: usize,
+        size: usize,
+    ) -> (usize, ManagedVec<StaticApi, FeedbackResponse<StaticApi>>) {
         self.world
             .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .agent_response(ManagedBuffer::from(job_id))
+            .get_responses(
+                agent_nonce,
+                client.to_managed_address(),
+                feedback_index,
+                from,
+                size,
+            )
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    /// `(next_cursor, responses)` from `getJobResponses`.
+    pub fn query_job_responses(
+        &mut self,
+        job_id: &[u8],
+        from: usize,
+        size: usize,
+    ) -> (usize, ManagedVec<StaticApi, FeedbackResponse<StaticApi>>) {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_job_responses(ManagedBuffer::from(job_id), from, size)
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
     // ── Upgrade helpers ──
@@ -992,6 +2714,34 @@ impl AgentTestState {
             .run();
     }
 
+    pub fn init_job_without_service_with_payment_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        token: &str,
+        amount: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::<u32>::None,
+            )
+            .esdt(TestEsdtTransfer(
+                multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
+                0,
+                amount,
+            ))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
     pub fn submit_proof_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1125,6 +2875,30 @@ impl AgentTestState {
             .run();
     }
 
+    pub fn append_feedback_response_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .append_feedback_response(
+                agent_nonce,
+                client.to_managed_address(),
+                feedback_index,
+                ManagedBuffer::from(b"https://response.uri"),
+                ManagedBuffer::new(),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
     pub fn init_job_with_wrong_token_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1256,6 +3030,39 @@ impl AgentTestState {
             .returns(ReturnsResult)
             .run()
     }
+
+    /// Whitebox helper: store an ERC-8004 feedback as a contract predating the feedback
+    /// aggregates would have, i.e. without counting it in `tagAggregate` / `feedbackSummary`.
+    pub fn seed_legacy_feedback(
+        &mut self,
+        client: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+        tag1: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(REPUTATION_SC_ADDRESS)
+            .whitebox(reputation_registry::contract_obj, |sc| {
+                let client_address = client.to_managed_address();
+                let index = sc
+                    .last_feedback_index(agent_nonce, &client_address)
+                    .update(|idx| {
+                        *idx += 1;
+                        *idx
+                    });
+                sc.feedback_data(agent_nonce, &client_address, index).set(
+                    reputation_registry::structs::FeedbackData {
+                        value,
+                        value_decimals: 0,
+                        tag1: ManagedBuffer::from(tag1),
+                        tag2: ManagedBuffer::new(),
+                        is_revoked: false,
+                    },
+                );
+            });
+    }
 }
 
 // ════════════════════════════════════════════════════════════
@@ -1629,6 +3436,58 @@ impl EscrowTestState {
             .run();
     }
 
+    pub fn open_dispute_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        reason_uri: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .open_dispute(ManagedBuffer::from(job_id), ManagedBuffer::from(reason_uri))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_validation_escrow_address(&mut self, address: ManagedAddress<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_escrow_contract_address(address)
+            .run();
+    }
+
+    pub fn clean_old_jobs(&mut self, job_ids: Vec<&[u8]>) {
+        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in &job_ids {
+            ids_encoded.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .clean_old_jobs(ids_encoded)
+            .run();
+    }
+
+    pub fn query_job_exists(&mut self, job_id: &[u8]) -> bool {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_data(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .is_some()
+    }
+
     // ── Escrow queries ──
 
     pub fn query_escrow(&mut self, job_id: &[u8]) -> EscrowData<StaticApi> {
@@ -1657,6 +3516,20 @@ impl EscrowTestState {
     /// to `Verified` — it only updates `ValidationRequestData`. This is a known gap
     /// in the validation registry design. This helper simulates the expected behavior
     /// for escrow release testing.
+    /// Whitebox helper: simulate a job that lost a dispute in the validation registry.
+    pub fn mark_job_rejected(&mut self, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .whitebox(validation_registry::contract_obj, |sc| {
+                let job_id_buf = ManagedBuffer::from(job_id);
+                sc.job_data(&job_id_buf).update(|job| {
+                    job.status = common::structs::JobStatus::Rejected;
+                });
+            });
+    }
+
     pub fn mark_job_verified(&mut self, job_id: &[u8]) {
         self.world
             .tx()
@@ -162,9 +162,7 @@ mod cs {
             .await;
 
         // Reputation (cross-contract storage reads from validation)
-        interact
-            .give_feedback_simple(&carol, b"job-001", agent_nonce, 85)
-            .await;
+        interact.give_feedback_simple(&carol, b"job-001", 85).await;
         interact
             .append_response(&bob, b"job-001", b"https://response.example.com/result")
             .await;
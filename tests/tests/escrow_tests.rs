@@ -656,3 +656,188 @@ fn test_full_lifecycle_esdt() {
     let escrow = state.query_escrow(b"lifecycle_esdt");
     assert_eq!(escrow.status, EscrowStatus::Released);
 }
+
+// ============================================
+// 19. Clean Old Jobs — active escrow keeps the job
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_skips_active_escrow() {
+    let mut state = EscrowTestState::new();
+    state.set_validation_escrow_address(state.escrow_sc.clone());
+
+    state.register_agent(
+        &AGENT_OWNER,
+        b"CleanupAgent",
+        b"https://cleanup.agent.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_seconds(0);
+    state.init_job(&EMPLOYER, b"cleanup_job", 1, None);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"cleanup_job",
+        &AGENT_OWNER,
+        b"poa_cleanup",
+        5 * 24 * 60 * 60,
+        1_000,
+    );
+
+    // Past the default 3-day retention, but the escrow is still active
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(4 * 24 * 60 * 60);
+    state.clean_old_jobs(vec![b"cleanup_job"]);
+    assert!(state.query_job_exists(b"cleanup_job"));
+
+    // Once the escrow is settled the job can go
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(6 * 24 * 60 * 60);
+    state.refund(&EMPLOYER, b"cleanup_job");
+    state.clean_old_jobs(vec![b"cleanup_job"]);
+    assert!(!state.query_job_exists(b"cleanup_job"));
+}
+
+// ============================================
+// 20. Refund before deadline — job rejected in a dispute
+// ============================================
+
+#[test]
+fn test_refund_rejected_job_before_deadline() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"DisputedAgent",
+        b"https://disputed.agent.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_seconds(100);
+    state.init_job(&EMPLOYER, b"rejected_job", 1, None);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"rejected_job",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        1_000,
+    );
+
+    state.refund_expect_err(&WORKER, b"rejected_job", "Deadline has not passed yet");
+
+    state.mark_job_rejected(b"rejected_job");
+    state.release_expect_err(
+        &EMPLOYER,
+        b"rejected_job",
+        "Job must be verified before release",
+    );
+    state.refund(&WORKER, b"rejected_job");
+
+    let escrow = state.query_escrow(b"rejected_job");
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+}
+
+// ============================================
+// 21. Clean Old Jobs — only the employer's escrow to the agent owner keeps the job
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_ignores_foreign_escrow() {
+    let mut state = EscrowTestState::new();
+    state.set_validation_escrow_address(state.escrow_sc.clone());
+
+    state.register_agent(
+        &AGENT_OWNER,
+        b"CleanupAgent",
+        b"https://cleanup.agent.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_seconds(0);
+    state.init_job(&EMPLOYER, b"dust_job", 1, None);
+    state.init_job(&EMPLOYER, b"misrouted_job", 1, None);
+
+    // A third party's dust escrow, and an employer escrow paying someone else
+    state.deposit_egld(
+        &WORKER,
+        b"dust_job",
+        &AGENT_OWNER,
+        b"poa_dust",
+        1_000 * 24 * 60 * 60,
+        1,
+    );
+    state.deposit_egld(
+        &EMPLOYER,
+        b"misrouted_job",
+        &WORKER,
+        b"poa_misrouted",
+        1_000 * 24 * 60 * 60,
+        1_000,
+    );
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(4 * 24 * 60 * 60);
+    state.clean_old_jobs(vec![b"dust_job", b"misrouted_job"]);
+    assert!(!state.query_job_exists(b"dust_job"));
+    assert!(!state.query_job_exists(b"misrouted_job"));
+}
+
+// ============================================
+// 22. Disputes — a third party's escrow does not make a job disputable
+// ============================================
+
+#[test]
+fn test_open_dispute_ignores_foreign_escrow() {
+    let mut state = EscrowTestState::new();
+    state.set_validation_escrow_address(state.escrow_sc.clone());
+
+    state.register_agent(
+        &AGENT_OWNER,
+        b"DisputedAgent",
+        b"https://disputed.agent.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_seconds(0);
+    state.init_job(&EMPLOYER, b"dust_job", 1, None);
+    state.init_job(&EMPLOYER, b"escrowed_job", 1, None);
+    state.submit_proof(&AGENT, b"dust_job", b"proof");
+    state.submit_proof(&AGENT, b"escrowed_job", b"proof");
+    state.deposit_egld(&WORKER, b"dust_job", &AGENT_OWNER, b"poa_dust", 1_000, 1);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"escrowed_job",
+        &AGENT_OWNER,
+        b"poa_escrowed",
+        1_000,
+        1_000,
+    );
+
+    state.open_dispute_expect_err(
+        &EMPLOYER,
+        b"dust_job",
+        b"ipfs://reason",
+        "Job has no held payment or active escrow to dispute",
+    );
+    // The employer's own escrow passes that check (and then needs an arbiter panel)
+    state.open_dispute_expect_err(
+        &EMPLOYER,
+        b"escrowed_job",
+        b"ipfs://reason",
+        "Not enough arbiters for the dispute quorum",
+    );
+}
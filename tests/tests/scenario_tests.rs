@@ -1,8 +1,12 @@
+#![allow(clippy::identity_op)]
+
 use common::structs::JobStatus;
+use ed25519_dalek::SigningKey;
 use multiversx_sc::proxy_imports::OptionalValue;
-use multiversx_sc::types::{BigUint, ManagedAddress, ManagedBuffer};
+use multiversx_sc::types::{BigInt, BigUint, ManagedAddress, ManagedBuffer, TimestampMillis};
 use multiversx_sc_scenario::api::StaticApi;
 use mx_8004_tests::{constants::*, setup::AgentTestState};
+use proxies::validation_registry_proxy::DisputeStatus;
 
 // ============================================
 // 1. Deploy
@@ -527,9 +531,10 @@ fn test_full_feedback_flow() {
 
     state.init_job(&CLIENT, b"job_fb", 1, None);
     state.submit_proof(&AGENT, b"job_fb", b"proof");
+    state.accept_delivery(&CLIENT, b"job_fb");
 
     // ERC-8004: employer (CLIENT) submits feedback directly — no authorization needed
-    state.give_feedback_simple(&CLIENT, b"job_fb", 1, 80);
+    state.give_feedback_simple(&CLIENT, b"job_fb", 80);
 
     // Verify reputation updated
     let score = state.query_reputation_score(1);
@@ -559,24 +564,23 @@ fn test_feedback_guards() {
 
     state.init_job(&CLIENT, b"job_guard", 1, None);
     state.submit_proof(&AGENT, b"job_guard", b"proof");
+    state.accept_delivery(&CLIENT, b"job_guard");
 
     // Non-employer tries to submit feedback -> error
     state.give_feedback_simple_expect_err(
         &WORKER,
         b"job_guard",
-        1,
         80,
         "Only the employer can provide feedback",
     );
 
     // Employer submits feedback (no authorize needed in ERC-8004)
-    state.give_feedback_simple(&CLIENT, b"job_guard", 1, 90);
+    state.give_feedback_simple(&CLIENT, b"job_guard", 90);
 
     // Duplicate feedback -> error
     state.give_feedback_simple_expect_err(
         &CLIENT,
         b"job_guard",
-        1,
         90,
         "Feedback already provided for this job",
     );
@@ -626,7 +630,7 @@ fn test_full_lifecycle() {
     );
 
     // 6. Submit feedback (employer, no authorize needed)
-    state.give_feedback_simple(&CLIENT, b"lifecycle_job", 1, 95);
+    state.give_feedback_simple(&CLIENT, b"lifecycle_job", 95);
     assert_eq!(
         state.query_reputation_score(1),
         BigUint::<StaticApi>::from(95u64)
@@ -1051,7 +1055,7 @@ fn test_submit_proof_agent_owner() {
 }
 
 // ============================================
-// 37. Submit Feedback — Employer can submit without validation
+// 37. Submit Feedback — Employer can submit without a validator
 // ============================================
 
 #[test]
@@ -1061,14 +1065,29 @@ fn test_give_feedback_simple_no_validation_needed() {
         &AGENT_OWNER,
         b"TestAgent",
         b"https://agent.example.com",
-        b"pubkey123",
+        AGENT.to_address().as_bytes(),
         vec![],
         vec![],
     );
 
     state.init_job(&CLIENT, b"job-no-val", 1, None);
-    // ERC-8004: employer can submit feedback without needing validation
-    state.give_feedback_simple(&CLIENT, b"job-no-val", 1, 80);
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"job-no-val",
+        80,
+        "Feedback requires a verified job",
+    );
+
+    // Accepting the delivery verifies the job — no validator needed
+    state.submit_proof(&AGENT, b"job-no-val", b"proof");
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"job-no-val",
+        80,
+        "Feedback requires a verified job",
+    );
+    state.accept_delivery(&CLIENT, b"job-no-val");
+    state.give_feedback_simple(&CLIENT, b"job-no-val", 80);
 
     let score = state.query_reputation_score(1);
     assert_eq!(score, BigUint::<StaticApi>::from(80u64));
@@ -1098,7 +1117,6 @@ fn test_give_feedback_simple_not_employer() {
     state.give_feedback_simple_expect_err(
         &WORKER,
         b"job-wrong-caller",
-        1,
         80,
         "Only the employer can provide feedback",
     );
@@ -1378,7 +1396,7 @@ fn test_is_job_verified_view() {
     state.validation_response(
         &VALIDATOR,
         b"req-verify-view",
-        1,
+        100,
         b"https://oracle.example.com/result",
         b"resp-verify-view",
         b"approved",
@@ -1405,7 +1423,7 @@ fn test_multi_job_reputation_average() {
     );
 
     // Job 1: rating 80
-    state.init_job(&OWNER_ADDRESS, b"rep_avg_1", 1, None);
+    state.init_job(&CLIENT, b"rep_avg_1", 1, None);
     state.submit_proof(&AGENT, b"rep_avg_1", b"proof-1");
     state.validation_request(
         &OWNER_ADDRESS,
@@ -1417,15 +1435,15 @@ fn test_multi_job_reputation_average() {
     state.validation_response(
         &VALIDATOR,
         b"req-avg-1",
-        1,
+        100,
         b"https://oracle.example.com/result",
         b"resp-avg-1",
         b"approved",
     );
-    state.give_feedback_simple(&OWNER_ADDRESS, b"rep_avg_1", 1, 80);
+    state.give_feedback_simple(&CLIENT, b"rep_avg_1", 80);
 
     // Job 2: rating 60
-    state.init_job(&OWNER_ADDRESS, b"rep_avg_2", 1, None);
+    state.init_job(&CLIENT, b"rep_avg_2", 1, None);
     state.submit_proof(&AGENT, b"rep_avg_2", b"proof-2");
     state.validation_request(
         &OWNER_ADDRESS,
@@ -1437,15 +1455,15 @@ fn test_multi_job_reputation_average() {
     state.validation_response(
         &VALIDATOR,
         b"req-avg-2",
-        1,
+        100,
         b"https://oracle.example.com/result",
         b"resp-avg-2",
         b"approved",
     );
-    state.give_feedback_simple(&OWNER_ADDRESS, b"rep_avg_2", 1, 60);
+    state.give_feedback_simple(&CLIENT, b"rep_avg_2", 60);
 
     // Job 3: rating 100
-    state.init_job(&OWNER_ADDRESS, b"rep_avg_3", 1, None);
+    state.init_job(&CLIENT, b"rep_avg_3", 1, None);
     state.submit_proof(&AGENT, b"rep_avg_3", b"proof-3");
     state.validation_request(
         &OWNER_ADDRESS,
@@ -1457,12 +1475,12 @@ fn test_multi_job_reputation_average() {
     state.validation_response(
         &VALIDATOR,
         b"req-avg-3",
-        1,
+        100,
         b"https://oracle.example.com/result",
         b"resp-avg-3",
         b"approved",
     );
-    state.give_feedback_simple(&OWNER_ADDRESS, b"rep_avg_3", 1, 100);
+    state.give_feedback_simple(&CLIENT, b"rep_avg_3", 100);
 
     // Average should be (80+60+100)/3 = 80
     let score = state.query_reputation_score(1);
@@ -1596,6 +1614,46 @@ fn test_validation_response_progressive() {
     );
     // Job should still be verified
     assert!(state.query_is_job_verified(b"job_progressive"));
+
+    // The request keeps the latest response; its URI is read from the history
+    let status = state
+        .query_validation_status(b"req-progressive")
+        .into_option()
+        .unwrap();
+    assert_eq!(status.response, 95);
+    let latest = state
+        .query_latest_validation_response(b"req-progressive")
+        .unwrap();
+    assert_eq!(
+        latest.response_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://oracle.example.com/final")
+    );
+    assert!(
+        state
+            .query_latest_validation_response(b"req-unknown")
+            .is_none()
+    );
+
+    // Both revisions are kept in the response history, oldest first
+    assert_eq!(state.query_validation_response_count(b"req-progressive"), 2);
+    let history = state.query_validation_responses(b"req-progressive", 0, 10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].response, 50);
+    assert_eq!(
+        history[0].response_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://oracle.example.com/partial")
+    );
+    assert_eq!(history[0].tag, ManagedBuffer::<StaticApi>::from(b"partial"));
+    assert_eq!(history[1].response, 95);
+    assert_eq!(
+        history[1].response_hash,
+        ManagedBuffer::<StaticApi>::from(b"resp-final")
+    );
+
+    // Pagination: offset past the first entry
+    let page = state.query_validation_responses(b"req-progressive", 1, 10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].response, 95);
 }
 
 // ============================================
@@ -1615,7 +1673,7 @@ fn test_give_feedback_simple_rating_boundaries() {
     );
 
     // Job 1: rating = 0 (lowest possible)
-    state.init_job(&OWNER_ADDRESS, b"job_boundary_0", 1, None);
+    state.init_job(&CLIENT, b"job_boundary_0", 1, None);
     state.submit_proof(&OWNER_ADDRESS, b"job_boundary_0", b"proof-zero");
     state.validation_request(
         &OWNER_ADDRESS,
@@ -1627,18 +1685,18 @@ fn test_give_feedback_simple_rating_boundaries() {
     state.validation_response(
         &VALIDATOR,
         b"req-boundary-0",
-        1,
+        100,
         b"https://oracle.example.com/result",
         b"resp-boundary-0",
         b"approved",
     );
-    state.give_feedback_simple(&OWNER_ADDRESS, b"job_boundary_0", 1, 0);
+    state.give_feedback_simple(&CLIENT, b"job_boundary_0", 0);
 
     let score_after_zero = state.query_reputation_score(1);
     assert_eq!(score_after_zero, 0, "Rating 0 → score should be 0");
 
     // Job 2: rating = 100 → avg should be (0 + 100) / 2 = 50
-    state.init_job(&OWNER_ADDRESS, b"job_boundary_100", 1, None);
+    state.init_job(&CLIENT, b"job_boundary_100", 1, None);
     state.submit_proof(&OWNER_ADDRESS, b"job_boundary_100", b"proof-hundred");
     state.validation_request(
         &OWNER_ADDRESS,
@@ -1650,12 +1708,12 @@ fn test_give_feedback_simple_rating_boundaries() {
     state.validation_response(
         &VALIDATOR,
         b"req-boundary-100",
-        1,
+        100,
         b"https://oracle.example.com/result",
         b"resp-boundary-100",
         b"approved",
     );
-    state.give_feedback_simple(&OWNER_ADDRESS, b"job_boundary_100", 1, 100);
+    state.give_feedback_simple(&CLIENT, b"job_boundary_100", 100);
 
     let score_after_hundred = state.query_reputation_score(1);
     assert_eq!(score_after_hundred, 50, "Average of (0 + 100) / 2 = 50");
@@ -1676,3 +1734,2802 @@ fn test_register_agent_token_not_issued() {
         "Token not issued",
     );
 }
+
+// ============================================
+// 55. Validation summary (ERC-8004 getSummary)
+// ============================================
+
+#[test]
+fn test_validation_summary_filters() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"SummaryBot",
+        b"https://example.com/manifest",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    // Three jobs: two validated by VALIDATOR, one by WORKER acting as validator
+    let jobs = [
+        (
+            &b"job_sum_1"[..],
+            &b"req-sum-1"[..],
+            VALIDATOR,
+            80u8,
+            &b"quality"[..],
+        ),
+        (
+            &b"job_sum_2"[..],
+            &b"req-sum-2"[..],
+            VALIDATOR,
+            60u8,
+            &b"speed"[..],
+        ),
+        (
+            &b"job_sum_3"[..],
+            &b"req-sum-3"[..],
+            WORKER,
+            100u8,
+            &b"quality"[..],
+        ),
+    ];
+    for (job_id, request_hash, validator, response, tag) in jobs {
+        state.init_job(&CLIENT, job_id, 1, None);
+        state.submit_proof(&AGENT, job_id, b"proof");
+        state.validation_request(
+            &AGENT_OWNER,
+            job_id,
+            &validator,
+            b"https://request.uri",
+            request_hash,
+        );
+        state.validation_response(
+            &validator,
+            request_hash,
+            response,
+            b"https://response.uri",
+            b"resp_hash",
+            tag,
+        );
+    }
+
+    // No filters: (80 + 60 + 100) / 3 = 80
+    assert_eq!(state.query_validation_summary(1, b"", vec![]), (3, 80));
+
+    // Tag filter
+    assert_eq!(
+        state.query_validation_summary(1, b"quality", vec![]),
+        (2, 90)
+    );
+
+    // Validator filter
+    assert_eq!(
+        state.query_validation_summary(1, b"", vec![&VALIDATOR]),
+        (2, 70)
+    );
+
+    // Validator + tag filter
+    assert_eq!(
+        state.query_validation_summary(1, b"quality", vec![&VALIDATOR, &WORKER]),
+        (2, 90)
+    );
+
+    // Progressive revision replaces the earlier contribution instead of adding to it
+    state.validation_response(
+        &VALIDATOR,
+        b"req-sum-2",
+        90,
+        b"https://response.uri",
+        b"resp_hash_v2",
+        b"quality",
+    );
+    assert_eq!(state.query_validation_summary(1, b"", vec![]), (3, 90));
+    assert_eq!(state.query_validation_summary(1, b"speed", vec![]), (0, 0));
+    assert_eq!(
+        state.query_validation_summary(1, b"quality", vec![]),
+        (3, 90)
+    );
+
+    // A used request hash cannot be re-requested on another job
+    state.init_job(&CLIENT, b"job_sum_4", 1, None);
+    state.submit_proof(&AGENT, b"job_sum_4", b"proof");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_sum_4",
+        &WORKER,
+        b"https://request.uri",
+        b"req-sum-1",
+        "Validation request hash already used",
+    );
+    assert_eq!(state.query_validation_summary(1, b"", vec![]), (3, 90));
+
+    // Unknown agent has an empty summary
+    assert_eq!(state.query_validation_summary(2, b"", vec![]), (0, 0));
+}
+
+// ============================================
+// 56. Paginated agent validations with filters
+// ============================================
+
+#[test]
+fn test_agent_validations_page() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"PagedBot",
+        b"https://example.com/manifest",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    let requests = [
+        (&b"job_page_1"[..], &b"req-page-1"[..], VALIDATOR),
+        (&b"job_page_2"[..], &b"req-page-2"[..], WORKER),
+        (&b"job_page_3"[..], &b"req-page-3"[..], VALIDATOR),
+    ];
+    for (job_id, request_hash, validator) in requests {
+        state.init_job(&CLIENT, job_id, 1, None);
+        state.submit_proof(&AGENT, job_id, b"proof");
+        state.validation_request(
+            &AGENT_OWNER,
+            job_id,
+            &validator,
+            b"https://request.uri",
+            request_hash,
+        );
+    }
+    state.validation_response(
+        &VALIDATOR,
+        b"req-page-1",
+        90,
+        b"https://response.uri",
+        b"resp_hash",
+        b"quality",
+    );
+
+    // Unfiltered, two pages of two
+    let (cursor, page) = state.query_agent_validations_page(1, 0, 2, None, None, None);
+    assert_eq!(cursor, 2);
+    assert_eq!(page.len(), 2);
+    let (cursor, page) = state.query_agent_validations_page(1, cursor, 2, None, None, None);
+    assert_eq!(cursor, 3);
+    assert_eq!(page, vec![ManagedBuffer::<StaticApi>::from(b"req-page-3")]);
+
+    // Validator filter
+    let (_, page) = state.query_agent_validations_page(1, 0, 10, Some(&VALIDATOR), None, None);
+    assert_eq!(
+        page,
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"req-page-1"),
+            ManagedBuffer::<StaticApi>::from(b"req-page-3"),
+        ]
+    );
+
+    // Status filter
+    let (_, page) = state.query_agent_validations_page(1, 0, 10, None, None, Some(false));
+    assert_eq!(page.len(), 2);
+    let (_, page) = state.query_agent_validations_page(1, 0, 10, None, None, Some(true));
+    assert_eq!(page, vec![ManagedBuffer::<StaticApi>::from(b"req-page-1")]);
+
+    // Tag filter only matches responded requests
+    let (_, page) = state.query_agent_validations_page(1, 0, 10, None, Some(b"quality"), None);
+    assert_eq!(page, vec![ManagedBuffer::<StaticApi>::from(b"req-page-1")]);
+
+    // Cursor past the end returns nothing
+    let (cursor, page) = state.query_agent_validations_page(1, 10, 10, None, None, None);
+    assert_eq!(cursor, 3);
+    assert!(page.is_empty());
+}
+
+// ============================================
+// 57. Paginated agent jobs and feedback clients
+// ============================================
+
+#[test]
+fn test_agent_jobs_and_clients_page() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"PagedBot",
+        b"https://example.com/manifest",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"job_a", 1, None);
+    state.init_job(&CLIENT, b"job_b", 1, None);
+    state.init_job(&WORKER, b"job_c", 1, None);
+
+    let (cursor, page) = state.query_agent_jobs_page(1, 0, 10, None);
+    assert_eq!(cursor, 3);
+    assert_eq!(
+        page,
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"job_a"),
+            ManagedBuffer::<StaticApi>::from(b"job_b"),
+            ManagedBuffer::<StaticApi>::from(b"job_c"),
+        ]
+    );
+
+    // Cleaning a job tombstones it: the rest keep their order and cursors stay valid
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![b"job_a"]);
+    let (cursor, page) = state.query_agent_jobs_page(1, 0, 10, None);
+    assert_eq!(cursor, 3);
+    assert_eq!(
+        page,
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"job_b"),
+            ManagedBuffer::<StaticApi>::from(b"job_c"),
+        ]
+    );
+    let (cursor, page) = state.query_agent_jobs_page(1, 2, 10, None);
+    assert_eq!(cursor, 3);
+    assert_eq!(page, vec![ManagedBuffer::<StaticApi>::from(b"job_c")]);
+
+    // A reused ID is indexed again at the end, never at its old slot
+    state.init_job(&CLIENT, b"job_a", 1, None);
+    let (cursor, page) = state.query_agent_jobs_page(1, 0, 10, None);
+    assert_eq!(cursor, 4);
+    assert_eq!(
+        page,
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"job_b"),
+            ManagedBuffer::<StaticApi>::from(b"job_c"),
+            ManagedBuffer::<StaticApi>::from(b"job_a"),
+        ]
+    );
+
+    // Feedback clients, paged one at a time
+    state.give_feedback(&CLIENT, 1, 80, 0, b"quality", b"");
+    state.give_feedback(&WORKER, 1, 70, 0, b"quality", b"");
+    state.give_feedback(&CLIENT, 1, 90, 0, b"speed", b"");
+
+    let (cursor, page) = state.query_clients_page(1, 0, 1);
+    assert_eq!(cursor, 1);
+    assert_eq!(page, vec![CLIENT.to_managed_address()]);
+    let (cursor, page) = state.query_clients_page(1, cursor, 1);
+    assert_eq!(cursor, 2);
+    assert_eq!(page, vec![WORKER.to_managed_address()]);
+    let (cursor, page) = state.query_clients_page(1, cursor, 1);
+    assert_eq!(cursor, 2);
+    assert!(page.is_empty());
+}
+
+// ============================================
+// 58. Employer job index and status filters
+// ============================================
+
+#[test]
+fn test_employer_jobs_page_with_status_filter() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"IndexBot",
+        b"https://example.com/manifest",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"emp_job_1", 1, None);
+    state.init_job(&CLIENT, b"emp_job_2", 1, None);
+    state.init_job(&WORKER, b"emp_job_3", 1, None);
+    state.submit_proof(&AGENT, b"emp_job_2", b"proof");
+
+    // Employer index only holds the employer's own jobs
+    let (cursor, page) = state.query_employer_jobs_page(&CLIENT, 0, 10, None);
+    assert_eq!(cursor, 2);
+    assert_eq!(
+        page,
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"emp_job_1"),
+            ManagedBuffer::<StaticApi>::from(b"emp_job_2"),
+        ]
+    );
+    let (_, page) = state.query_employer_jobs_page(&WORKER, 0, 10, None);
+    assert_eq!(page, vec![ManagedBuffer::<StaticApi>::from(b"emp_job_3")]);
+
+    // Status filters on both indexes
+    let (_, page) = state.query_employer_jobs_page(&CLIENT, 0, 10, Some(JobStatus::Pending));
+    assert_eq!(page, vec![ManagedBuffer::<StaticApi>::from(b"emp_job_2")]);
+    let (_, page) = state.query_agent_jobs_page(1, 0, 10, Some(JobStatus::New));
+    assert_eq!(
+        page,
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"emp_job_1"),
+            ManagedBuffer::<StaticApi>::from(b"emp_job_3"),
+        ]
+    );
+
+    // clean_old_jobs keeps the employer index consistent
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![b"emp_job_1", b"emp_job_3"]);
+
+    let (cursor, page) = state.query_employer_jobs_page(&CLIENT, 0, 10, None);
+    assert_eq!(cursor, 2);
+    assert_eq!(page, vec![ManagedBuffer::<StaticApi>::from(b"emp_job_2")]);
+    let (cursor, page) = state.query_employer_jobs_page(&WORKER, 0, 10, None);
+    assert_eq!(cursor, 1);
+    assert!(page.is_empty());
+}
+
+// ============================================
+// 59. Init Job — Unknown agent
+// ============================================
+
+#[test]
+fn test_init_job_unknown_agent() {
+    let mut state = AgentTestState::new();
+
+    // No agent registered yet
+    state.init_job_expect_err(&CLIENT, b"job_ghost", 1, None, "Agent not found");
+
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job_expect_err(&CLIENT, b"job_ghost", 2, None, "Agent not found");
+    assert!(state.query_job_data(b"job_ghost").is_none());
+}
+
+// ============================================
+// 60. Init Job — Unknown service ID
+// ============================================
+
+#[test]
+fn test_init_job_unknown_service() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_expect_err(
+        &CLIENT,
+        b"job_no_svc",
+        1,
+        Some(7),
+        "Service config not found for agent",
+    );
+
+    // A payment for an unknown service is rejected, not stranded
+    state.init_job_with_payment_expect_err(
+        &CLIENT,
+        b"job_no_svc",
+        1,
+        7,
+        "USDC-abcdef",
+        0,
+        100,
+        "Service config not found for agent",
+    );
+    assert!(state.query_job_data(b"job_no_svc").is_none());
+}
+
+// ============================================
+// 61. Init Job — Payment without service ID
+// ============================================
+
+#[test]
+fn test_init_job_payment_without_service() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_without_service_with_payment_expect_err(
+        &CLIENT,
+        b"job_unsolicited",
+        1,
+        "USDC-abcdef",
+        100,
+        "Payment not accepted without a service ID",
+    );
+    assert!(state.query_job_data(b"job_unsolicited").is_none());
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+}
+
+// ============================================
+// 62. create_job — generated job IDs
+// ============================================
+
+#[test]
+fn test_create_job_generated_ids() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    let first = state.create_job(&CLIENT, 1);
+    let second = state.create_job(&CLIENT, 1);
+    assert_eq!(first.len(), 32);
+    assert_ne!(first, second);
+
+    // Generated IDs are regular job IDs: readable cross-contract by reputation/escrow
+    let job = state.query_job_data(&first).into_option().unwrap();
+    assert_eq!(job.employer, CLIENT.to_managed_address());
+    assert_eq!(job.status, JobStatus::New);
+
+    state.submit_proof(&AGENT, &first, b"proof");
+    state.accept_delivery(&CLIENT, &first);
+    state.give_feedback_simple(&CLIENT, &first, 90);
+    assert!(state.query_has_given_feedback(&first));
+}
+
+// ============================================
+// 63. create_job — squatted ID is skipped
+// ============================================
+
+#[test]
+fn test_create_job_skips_squatted_id() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    // Attacker precomputes CLIENT's next generated ID and front-runs it with init_job
+    let predicted = state.compute_job_id(&CLIENT, 1);
+    state.init_job(&WORKER, &predicted, 1, None);
+
+    // CLIENT still gets a fresh job it owns
+    let job_id = state.create_job(&CLIENT, 1);
+    assert_ne!(job_id, predicted);
+    assert_eq!(job_id, state.compute_job_id(&CLIENT, 2));
+    let job = state.query_job_data(&job_id).into_option().unwrap();
+    assert_eq!(job.employer, CLIENT.to_managed_address());
+}
+
+// ============================================
+// 64. Clean Old Jobs — per-status retention
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_retention_per_status() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    let day_ms: u64 = 24 * 60 * 60 * 1000;
+    assert_eq!(
+        state.query_job_retention(JobStatus::New).as_u64_millis(),
+        3 * day_ms
+    );
+
+    state.set_job_retention_expect_err(
+        &CLIENT,
+        JobStatus::New,
+        day_ms,
+        "Endpoint can only be called by owner",
+    );
+    state.set_job_retention(&OWNER_ADDRESS, JobStatus::New, day_ms);
+    state.set_job_retention(&OWNER_ADDRESS, JobStatus::Verified, 30 * day_ms);
+    assert_eq!(
+        state.query_job_retention(JobStatus::New).as_u64_millis(),
+        day_ms
+    );
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"job_new", 1, None);
+    state.init_job(&CLIENT, b"job_pending", 1, None);
+    state.submit_proof(&AGENT, b"job_pending", b"proof");
+    state.init_job(&CLIENT, b"job_verified", 1, None);
+    state.submit_proof(&AGENT, b"job_verified", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_verified",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"ret_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"ret_hash",
+        90,
+        b"https://resp.uri",
+        b"ret_resp",
+        b"quality",
+    );
+
+    // After 2 days only the New job (1 day retention) is gone
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(2 * day_ms);
+    state.clean_old_jobs(vec![b"job_new", b"job_pending", b"job_verified"]);
+    assert!(state.query_job_data(b"job_new").is_none());
+    assert!(state.query_job_data(b"job_pending").is_some());
+    assert!(state.query_job_data(b"job_verified").is_some());
+
+    // After 4 days Pending (default 3 days) is gone, Verified (30 days) is kept
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(4 * day_ms);
+    state.clean_old_jobs(vec![b"job_pending", b"job_verified"]);
+    assert!(state.query_job_data(b"job_pending").is_none());
+    assert!(state.query_job_data(b"job_verified").is_some());
+}
+
+// ============================================
+// 65. Clean Old Jobs — verified job awaiting feedback is kept
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_keeps_jobs_awaiting_feedback() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+    state.set_validation_reputation_address(REPUTATION_SC_ADDRESS.to_managed_address());
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"job_fb", 1, None);
+    state.submit_proof(&AGENT, b"job_fb", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_fb",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"fb_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"fb_hash",
+        80,
+        b"https://resp.uri",
+        b"fb_resp",
+        b"quality",
+    );
+
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+
+    // Employer has not rated yet: cleanup must not take the review away
+    state.clean_old_jobs(vec![b"job_fb"]);
+    assert!(state.query_job_data(b"job_fb").is_some());
+
+    state.give_feedback_simple(&CLIENT, b"job_fb", 80);
+    state.clean_old_jobs(vec![b"job_fb"]);
+    assert!(state.query_job_data(b"job_fb").is_some());
+
+    // Nor the agent owner's rating of the employer
+    state.rate_employer(&AGENT_OWNER, b"job_fb", 80);
+    state.clean_old_jobs(vec![b"job_fb"]);
+    assert!(state.query_job_data(b"job_fb").is_none());
+
+    // An unrated job is only kept for the feedback window after verification
+    state.set_feedback_window_expect_err(0, "Feedback window must be greater than zero");
+    let five_days_ms: u64 = 5 * 24 * 60 * 60 * 1000;
+    state.set_feedback_window(five_days_ms);
+    state.init_job(&CLIENT, b"job_unrated", 1, None);
+    state.submit_proof(&AGENT, b"job_unrated", b"proof");
+    state.accept_delivery(&CLIENT, b"job_unrated");
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms + five_days_ms);
+    state.clean_old_jobs(vec![b"job_unrated"]);
+    assert!(state.query_job_data(b"job_unrated").is_some());
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms + five_days_ms + 1);
+    state.clean_old_jobs(vec![b"job_unrated"]);
+    assert!(state.query_job_data(b"job_unrated").is_none());
+}
+
+// ============================================
+// 66. Held payment — released to agent owner on verification
+// ============================================
+
+#[test]
+fn test_held_payment_released_on_verification() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_held(&CLIENT, b"held_job", 1, 1, 100);
+    let held = state.query_held_payment(b"held_job").unwrap();
+    assert_eq!(held.amount.as_big_uint(), &BigUint::from(100u64));
+    state
+        .world
+        .check_account(VALIDATION_SC_ADDRESS)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+
+    state.submit_proof(&AGENT, b"held_job", b"proof");
+    state.approve_validator(&CLIENT, b"held_job", &VALIDATOR);
+    state.validation_request(
+        &AGENT_OWNER,
+        b"held_job",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"held_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"held_hash",
+        90,
+        b"https://resp.uri",
+        b"held_resp",
+        b"quality",
+    );
+
+    assert!(state.query_held_payment(b"held_job").is_none());
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+    state.claim_refund_expect_err(&CLIENT, b"held_job", "No held payment for this job");
+}
+
+// ============================================
+// 67. cancel_job — employer cancels before proof
+// ============================================
+
+#[test]
+fn test_cancel_job() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_held(&CLIENT, b"cancel_job", 1, 1, 100);
+    state.cancel_job_expect_err(&WORKER, b"cancel_job", "Only the employer can call this");
+
+    state.cancel_job(&CLIENT, b"cancel_job");
+    let job = state.query_job_data(b"cancel_job").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Cancelled);
+    assert!(state.query_held_payment(b"cancel_job").is_none());
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+
+    // A cancelled job accepts no more work
+    state.submit_proof_expect_err(
+        &AGENT,
+        b"cancel_job",
+        b"proof",
+        "Job status invalid for proof submission",
+    );
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"cancel_job",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"cancel_hash",
+        "Job has been cancelled",
+    );
+
+    // Once the agent has delivered, the employer can no longer back out
+    state.init_job_held(&CLIENT, b"delivered_job", 1, 1, 100);
+    state.submit_proof(&AGENT, b"delivered_job", b"proof");
+    state.cancel_job_expect_err(
+        &CLIENT,
+        b"delivered_job",
+        "Job can only be cancelled before proof submission",
+    );
+
+    // Direct payments are already with the agent owner: nothing to cancel
+    state.init_job_with_payment(&CLIENT, b"direct_job", 1, 1, "USDC-abcdef", 0, 100);
+    state.cancel_job_expect_err(&CLIENT, b"direct_job", "No held payment for this job");
+    let job = state.query_job_data(b"direct_job").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::New);
+}
+
+// ============================================
+// 68. claim_refund — held payment after timeout
+// ============================================
+
+#[test]
+fn test_claim_refund_after_timeout() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    let day_ms: u64 = 24 * 60 * 60 * 1000;
+    state.set_refund_timeout(2 * day_ms);
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job_held(&CLIENT, b"slow_job", 1, 1, 100);
+    state.submit_proof(&AGENT, b"slow_job", b"proof");
+    state.approve_validator(&CLIENT, b"slow_job", &VALIDATOR);
+    state.validation_request(
+        &AGENT_OWNER,
+        b"slow_job",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"slow_hash",
+    );
+
+    state.world.current_block().block_timestamp_millis(day_ms);
+    state.claim_refund_expect_err(&CLIENT, b"slow_job", "Refund timeout has not passed yet");
+
+    // Held payments are never cleaned up with the job
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(4 * day_ms);
+    state.clean_old_jobs(vec![b"slow_job"]);
+    assert!(state.query_job_data(b"slow_job").is_some());
+
+    state.claim_refund_expect_err(&WORKER, b"slow_job", "Only the employer can call this");
+    state.claim_refund(&CLIENT, b"slow_job");
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+
+    // A late validator response can no longer verify (and pay out) the job
+    state.validation_response_expect_err(
+        &VALIDATOR,
+        b"slow_hash",
+        90,
+        b"https://resp.uri",
+        b"slow_resp",
+        b"quality",
+        "Job has been cancelled",
+    );
+
+    // A fresh proof restarts the timeout: the agent keeps the time it needs to deliver
+    state.init_job_held(&CLIENT, b"late_job", 1, 1, 100);
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(5 * day_ms);
+    state.submit_proof(&AGENT, b"late_job", b"proof");
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(7 * day_ms);
+    state.claim_refund_expect_err(&CLIENT, b"late_job", "Refund timeout has not passed yet");
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(7 * day_ms + 1);
+    state.claim_refund(&CLIENT, b"late_job");
+}
+
+// ============================================
+// 69. Structured proof submissions with history
+// ============================================
+
+#[test]
+fn test_proof_history() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_millis(1_000);
+    state.init_job(&CLIENT, b"proof_job", 1, None);
+    state.submit_proof(&AGENT, b"proof_job", b"draft-hash");
+
+    state.world.current_block().block_timestamp_millis(2_000);
+    state.submit_proof_with_details(
+        &AGENT_OWNER,
+        b"proof_job",
+        b"final-hash",
+        b"ipfs://final",
+        b"application/json",
+    );
+
+    // The job keeps the latest proof, the history keeps every submission
+    let job = state.query_job_data(b"proof_job").into_option().unwrap();
+    assert_eq!(job.proof, ManagedBuffer::<StaticApi>::from(b"final-hash"));
+    assert_eq!(job.status, JobStatus::Pending);
+
+    let proofs = state.query_job_proofs(b"proof_job", 0, 10);
+    assert_eq!(proofs.len(), 2);
+    assert_eq!(
+        proofs[0].proof_hash,
+        ManagedBuffer::<StaticApi>::from(b"draft-hash")
+    );
+    assert!(proofs[0].uri.is_empty());
+    assert_eq!(proofs[0].submitter, AGENT.to_managed_address());
+    assert_eq!(proofs[0].timestamp.as_u64_millis(), 1_000);
+    assert_eq!(
+        proofs[1].proof_hash,
+        ManagedBuffer::<StaticApi>::from(b"final-hash")
+    );
+    assert_eq!(
+        proofs[1].uri,
+        ManagedBuffer::<StaticApi>::from(b"ipfs://final")
+    );
+    assert_eq!(
+        proofs[1].content_type,
+        ManagedBuffer::<StaticApi>::from(b"application/json")
+    );
+    assert_eq!(proofs[1].submitter, AGENT_OWNER.to_managed_address());
+    assert_eq!(proofs[1].timestamp.as_u64_millis(), 2_000);
+
+    // The history is paginated and capped per job
+    let page = state.query_job_proofs(b"proof_job", 1, 10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page[0].proof_hash,
+        ManagedBuffer::<StaticApi>::from(b"final-hash")
+    );
+    for _ in 2..20 {
+        state.submit_proof(&AGENT, b"proof_job", b"revision");
+    }
+    state.submit_proof_expect_err(
+        &AGENT,
+        b"proof_job",
+        b"one-too-many",
+        "Proof limit reached for this job",
+    );
+    assert_eq!(state.query_job_proof_count(b"proof_job"), 20);
+
+    // Same guards as submit_proof
+    state.submit_proof_expect_err(
+        &CLIENT,
+        b"proof_job",
+        b"fake",
+        "Only the agent owner can perform this action",
+    );
+
+    // History goes away with the job
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![b"proof_job"]);
+    assert!(state.query_job_proofs(b"proof_job", 0, 10).is_empty());
+}
+
+// ============================================
+// 70. Job timing metrics and SLA compliance
+// ============================================
+
+#[test]
+fn test_job_timing_metrics() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    let minute_ms: u64 = 60 * 1000;
+    state.set_service_sla_expect_err(
+        &CLIENT,
+        1,
+        1,
+        60 * minute_ms,
+        "Only the agent owner can perform this action",
+    );
+    state.set_service_sla_expect_err(
+        &AGENT_OWNER,
+        1,
+        7,
+        60 * minute_ms,
+        "Service config not found for agent",
+    );
+    state.set_service_sla(&AGENT_OWNER, 1, 1, 60 * minute_ms);
+
+    let verify = |state: &mut AgentTestState, job_id: &[u8], hash: &[u8]| {
+        state.validation_request(&AGENT_OWNER, job_id, &VALIDATOR, b"https://val.uri", hash);
+        state.validation_response(
+            &VALIDATOR,
+            hash,
+            90,
+            b"https://resp.uri",
+            b"resp",
+            b"quality",
+        );
+    };
+
+    // Job A: SLA 60 min, delivered after 30 min
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job_with_payment(&CLIENT, b"job_a", 1, 1, "USDC-abcdef", 0, 100);
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(30 * minute_ms);
+    state.submit_proof(&AGENT, b"job_a", b"proof");
+    state.submit_proof(&AGENT, b"job_a", b"proof-v2");
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(40 * minute_ms);
+    verify(&mut state, b"job_a", b"hash_a");
+
+    let timeline = state.query_job_timeline(b"job_a");
+    assert_eq!(timeline.first_proof_at.as_u64_millis(), 30 * minute_ms);
+    assert_eq!(
+        timeline.validation_requested_at.as_u64_millis(),
+        40 * minute_ms
+    );
+    assert_eq!(timeline.verified_at.as_u64_millis(), 40 * minute_ms);
+
+    // A revised response does not count the job twice
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(50 * minute_ms);
+    state.validation_response(
+        &VALIDATOR,
+        b"hash_a",
+        95,
+        b"https://resp.uri",
+        b"resp2",
+        b"quality",
+    );
+    assert_eq!(
+        state
+            .query_job_timeline(b"job_a")
+            .verified_at
+            .as_u64_millis(),
+        40 * minute_ms
+    );
+
+    // Job B: SLA 60 min, delivered after 120 min (late)
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(60 * minute_ms);
+    state.init_job_with_payment(&CLIENT, b"job_b", 1, 1, "USDC-abcdef", 0, 100);
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(180 * minute_ms);
+    state.submit_proof(&AGENT, b"job_b", b"proof");
+    verify(&mut state, b"job_b", b"hash_b");
+
+    // Job C: no service, so no SLA; delivered after 10 min
+    state.init_job(&CLIENT, b"job_c", 1, None);
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(190 * minute_ms);
+    state.submit_proof(&AGENT, b"job_c", b"proof");
+    verify(&mut state, b"job_c", b"hash_c");
+
+    // avg = (30 + 120 + 10) / 3 min, on-time = 1 of 2 SLA-bound jobs
+    let (delivered, avg_ms, on_time_bps) = state.query_agent_delivery_stats(1);
+    assert_eq!(delivered, 3);
+    assert_eq!(avg_ms, 160 * minute_ms / 3);
+    assert_eq!(on_time_bps, 5_000);
+
+    let metrics = state.query_reputation_delivery_metrics(1);
+    assert_eq!(metrics.delivered_jobs, 3);
+    assert_eq!(metrics.total_delivery_ms, 160 * minute_ms);
+    assert_eq!(metrics.sla_jobs, 2);
+    assert_eq!(metrics.on_time_jobs, 1);
+}
+
+// ============================================
+// 71. accept_delivery — employer verifies directly
+// ============================================
+
+#[test]
+fn test_accept_delivery() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_held(&CLIENT, b"accept_job", 1, 1, 100);
+    state.accept_delivery_expect_err(&CLIENT, b"accept_job", "Job has no delivered proof");
+
+    state.submit_proof(&AGENT, b"accept_job", b"proof");
+    state.accept_delivery_expect_err(&WORKER, b"accept_job", "Only the employer can call this");
+
+    state.accept_delivery(&CLIENT, b"accept_job");
+    assert!(state.query_is_job_verified(b"accept_job"));
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+    state.accept_delivery_expect_err(&CLIENT, b"accept_job", "Job already verified");
+
+    // An agent owner hiring their own agent cannot verify or rate the job themselves
+    state.init_job(&AGENT_OWNER, b"self_job", 1, None);
+    state.enable_optimistic_verification_expect_err(
+        &AGENT_OWNER,
+        b"self_job",
+        60_000,
+        "Agent owner cannot verify a job for their own agent",
+    );
+    state.submit_proof(&AGENT, b"self_job", b"proof");
+    state.accept_delivery_expect_err(
+        &AGENT_OWNER,
+        b"self_job",
+        "Agent owner cannot verify a job for their own agent",
+    );
+    state.validation_request(
+        &AGENT_OWNER,
+        b"self_job",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"self_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"self_hash",
+        100,
+        b"https://resp.uri",
+        b"self_resp",
+        b"quality",
+    );
+    state.give_feedback_simple_expect_err(
+        &AGENT_OWNER,
+        b"self_job",
+        100,
+        "Agent owner cannot give feedback to own agent",
+    );
+    state.rate_employer_expect_err(
+        &AGENT_OWNER,
+        b"self_job",
+        100,
+        "Agent owner cannot give feedback to own agent",
+    );
+}
+
+// ============================================
+// 72. Optimistic verification — finalize after window, challenge blocks it
+// ============================================
+
+#[test]
+fn test_optimistic_verification() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    let hour_ms: u64 = 60 * 60 * 1000;
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"opt_job", 1, None);
+    state.init_job(&CLIENT, b"disputed_job", 1, None);
+    state.init_job(&CLIENT, b"plain_job", 1, None);
+    state.enable_optimistic_verification_expect_err(
+        &CLIENT,
+        b"opt_job",
+        31 * 24 * hour_ms,
+        "Challenge window must be between 1 ms and 30 days",
+    );
+    state.enable_optimistic_verification_expect_err(
+        &CLIENT,
+        b"opt_job",
+        0,
+        "Challenge window must be between 1 ms and 30 days",
+    );
+    state.enable_optimistic_verification(&CLIENT, b"opt_job", 24 * hour_ms);
+    state.enable_optimistic_verification(&CLIENT, b"disputed_job", 24 * hour_ms);
+
+    state.world.current_block().block_timestamp_millis(hour_ms);
+    state.submit_proof(&AGENT, b"opt_job", b"proof");
+    state.submit_proof(&AGENT, b"disputed_job", b"proof");
+    state.submit_proof(&AGENT, b"plain_job", b"proof");
+    state.enable_optimistic_verification_expect_err(
+        &CLIENT,
+        b"plain_job",
+        24 * hour_ms,
+        "Optimistic verification can only be enabled before delivery",
+    );
+
+    state.finalize_job_expect_err(&WORKER, b"opt_job", "Challenge window has not ended yet");
+    state.finalize_job_expect_err(
+        &WORKER,
+        b"plain_job",
+        "Optimistic verification not enabled for this job",
+    );
+    state.challenge_delivery(&CLIENT, b"disputed_job");
+
+    // Window runs from the latest proof
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(25 * hour_ms + 1);
+    state.finalize_job(&WORKER, b"opt_job");
+    assert!(state.query_is_job_verified(b"opt_job"));
+
+    state.finalize_job_expect_err(&WORKER, b"disputed_job", "Delivery has been challenged");
+    state.challenge_delivery_expect_err(
+        &CLIENT,
+        b"plain_job",
+        "Optimistic verification not enabled for this job",
+    );
+    assert!(!state.query_is_job_verified(b"disputed_job"));
+
+    // A challenged job can still be verified by the employer
+    state.accept_delivery(&CLIENT, b"disputed_job");
+    assert!(state.query_is_job_verified(b"disputed_job"));
+}
+
+// ============================================
+// 73. Disputes — setup and guards
+// ============================================
+
+#[test]
+fn test_dispute_guards() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.init_job_held(&CLIENT, b"dispute_job", 1, 1, 100);
+
+    state.open_dispute_expect_err(
+        &CLIENT,
+        b"dispute_job",
+        b"ipfs://reason",
+        "Job cannot be disputed in its current status",
+    );
+    state.submit_proof(&AGENT, b"dispute_job", b"proof");
+    state.open_dispute_expect_err(
+        &CLIENT,
+        b"dispute_job",
+        b"ipfs://reason",
+        "Not enough arbiters for the dispute quorum",
+    );
+
+    state.add_arbiters(vec![&ARBITER_1, &ARBITER_2, &ARBITER_3]);
+    state.set_dispute_quorum(2);
+    state.open_dispute_expect_err(
+        &WORKER,
+        b"dispute_job",
+        b"ipfs://reason",
+        "Only the employer or the agent owner can call this",
+    );
+
+    // Nothing left to dispute once the payment went to the agent owner
+    state.init_job_with_payment(&CLIENT, b"direct_job", 1, 1, "USDC-abcdef", 0, 100);
+    state.submit_proof(&AGENT, b"direct_job", b"proof");
+    state.open_dispute_expect_err(
+        &CLIENT,
+        b"direct_job",
+        b"ipfs://reason",
+        "Job has no held payment or active escrow to dispute",
+    );
+
+    state.open_dispute(&CLIENT, b"dispute_job", b"ipfs://reason");
+    let job = state.query_job_data(b"dispute_job").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Disputed);
+    state.open_dispute_expect_err(
+        &AGENT_OWNER,
+        b"dispute_job",
+        b"ipfs://other",
+        "Job cannot be disputed in its current status",
+    );
+
+    // The job is frozen while the panel decides
+    state.accept_delivery_expect_err(&CLIENT, b"dispute_job", "Job is under dispute");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"dispute_job",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"dispute_hash",
+        "Job is under dispute",
+    );
+
+    state.vote_dispute_expect_err(
+        &WORKER,
+        b"dispute_job",
+        true,
+        "Only a registered arbiter can vote",
+    );
+    state.vote_dispute(&ARBITER_1, b"dispute_job", true);
+    state.vote_dispute_expect_err(
+        &ARBITER_1,
+        b"dispute_job",
+        true,
+        "Arbiter already voted on this dispute",
+    );
+    let dispute = state.query_dispute(b"dispute_job").unwrap();
+    assert_eq!(dispute.votes_for_agent, 1);
+    assert_eq!(dispute.opener, CLIENT.to_managed_address());
+}
+
+// ============================================
+// 74. Disputes — employer wins, held payment and bond returned
+// ============================================
+
+#[test]
+fn test_dispute_employer_wins() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.add_arbiters(vec![&ARBITER_1, &ARBITER_2, &ARBITER_3]);
+    state.set_dispute_quorum(2);
+    state.set_dispute_bond(10);
+
+    state.init_job_held(&CLIENT, b"bad_job", 1, 1, 100);
+    state.submit_proof(&AGENT, b"bad_job", b"garbage");
+    state.open_dispute_expect_err(
+        &CLIENT,
+        b"bad_job",
+        b"ipfs://reason",
+        "Invalid dispute bond",
+    );
+    state.open_dispute_with_bond(&CLIENT, b"bad_job", b"ipfs://reason", 10);
+
+    state.vote_dispute(&ARBITER_1, b"bad_job", false);
+    state.vote_dispute(&ARBITER_2, b"bad_job", true);
+    state.vote_dispute(&ARBITER_3, b"bad_job", false);
+
+    let job = state.query_job_data(b"bad_job").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Rejected);
+    assert!(state.query_held_payment(b"bad_job").is_none());
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+
+    state.vote_dispute_expect_err(&ARBITER_1, b"bad_job", false, "Dispute is not open");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"bad_job",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"bad_hash",
+        "Job was rejected in a dispute",
+    );
+}
+
+// ============================================
+// 75. Disputes — agent wins, job verified and bond forfeited
+// ============================================
+
+#[test]
+fn test_dispute_agent_wins() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.add_arbiters(vec![&ARBITER_1, &ARBITER_2, &ARBITER_3]);
+    state.set_dispute_quorum(2);
+    state.set_dispute_bond(10);
+
+    state.init_job_held(&CLIENT, b"good_job", 1, 1, 100);
+    state.submit_proof(&AGENT, b"good_job", b"proof");
+    state.open_dispute_with_bond(&CLIENT, b"good_job", b"ipfs://reason", 10);
+
+    state.vote_dispute(&ARBITER_2, b"good_job", true);
+    state.vote_dispute(&ARBITER_3, b"good_job", true);
+
+    assert!(state.query_is_job_verified(b"good_job"));
+    let dispute = state.query_dispute(b"good_job").unwrap();
+    assert_eq!(dispute.votes_for_agent, 2);
+
+    // Held payment released and the losing employer's bond goes to the agent owner
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 110u64);
+    state.open_dispute_expect_err(
+        &CLIENT,
+        b"good_job",
+        b"ipfs://again",
+        "A dispute was already opened for this job",
+    );
+}
+
+// ============================================
+// 76. Batch init_jobs / submit_proofs
+// ============================================
+
+#[test]
+fn test_batch_jobs_and_proofs() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![
+            (1u32, 100u64, b"USDC-abcdef", 0u64),
+            (2u32, 50u64, b"USDC-abcdef", 0u64),
+        ],
+    );
+
+    // Payments are matched in order to the jobs that name a service
+    state.init_jobs_expect_err(
+        &CLIENT,
+        vec![(b"batch_1", 1, Some(1)), (b"batch_2", 1, Some(2))],
+        vec![100],
+        "Number of payments must match the number of jobs with a paid service",
+    );
+    state.init_jobs_expect_err(
+        &CLIENT,
+        vec![(b"batch_1", 1, Some(1)), (b"batch_2", 1, None)],
+        vec![100, 50],
+        "Number of payments must match the number of jobs with a paid service",
+    );
+    state.init_jobs_expect_err(
+        &CLIENT,
+        vec![(b"batch_1", 1, Some(2)), (b"batch_2", 1, Some(1))],
+        vec![100, 50],
+        "Insufficient payment",
+    );
+    state.init_jobs_expect_err(
+        &CLIENT,
+        vec![(b"batch_1", 1, None), (b"batch_1", 1, None)],
+        vec![],
+        "Job already initialized",
+    );
+    // Only jobs for an existing, non-free service take a payment
+    state.init_jobs_expect_err(
+        &CLIENT,
+        vec![(b"batch_1", 1, Some(9)), (b"batch_2", 1, Some(1))],
+        vec![100],
+        "Service config not found for agent",
+    );
+    assert!(state.query_job_data(b"batch_1").is_none());
+
+    state.init_jobs(
+        &CLIENT,
+        vec![
+            (b"batch_1", 1, Some(1)),
+            (b"batch_2", 1, None),
+            (b"batch_3", 1, Some(2)),
+        ],
+        vec![100, 50],
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 150u64);
+    for job_id in [&b"batch_1"[..], b"batch_2", b"batch_3"] {
+        let job = state.query_job_data(job_id).into_option().unwrap();
+        assert_eq!(job.status, JobStatus::New);
+        assert_eq!(job.employer, CLIENT.to_managed_address());
+    }
+
+    // One unauthorized entry reverts the whole batch
+    state.submit_proofs_expect_err(
+        &CLIENT,
+        vec![(b"batch_1", b"p1"), (b"batch_2", b"p2")],
+        "Only the agent owner can perform this action",
+    );
+    state.submit_proofs(
+        &AGENT,
+        vec![
+            (b"batch_1", b"p1"),
+            (b"batch_2", b"p2"),
+            (b"batch_3", b"p3"),
+        ],
+    );
+    for (job_id, proof) in [
+        (&b"batch_1"[..], &b"p1"[..]),
+        (b"batch_2", b"p2"),
+        (b"batch_3", b"p3"),
+    ] {
+        let job = state.query_job_data(job_id).into_option().unwrap();
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.proof, ManagedBuffer::<StaticApi>::from(proof));
+        assert_eq!(state.query_job_proofs(job_id, 0, 10).len(), 1);
+    }
+}
+
+// ============================================
+// 77. Relayed proof submission signed by the agent key
+// ============================================
+
+#[test]
+fn test_submit_proof_signed() {
+    let mut state = AgentTestState::new();
+    let agent_key = SigningKey::from_bytes(&[7u8; 32]);
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        &agent_key.verifying_key().to_bytes(),
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_signed", 1, None);
+    state.world.current_block().block_timestamp_millis(1_000);
+    let expiry = TimestampMillis::new(5_000);
+
+    // Signed by a different key
+    let other_key = SigningKey::from_bytes(&[9u8; 32]);
+    let bad_sig = state.sign_proof(&other_key, b"job_signed", b"proof_1", expiry);
+    state.submit_proof_signed_expect_err(
+        &CLIENT,
+        b"job_signed",
+        b"proof_1",
+        &bad_sig,
+        expiry,
+        "ed25519 verify error",
+    );
+
+    // Signature does not cover a different proof
+    let sig = state.sign_proof(&agent_key, b"job_signed", b"proof_1", expiry);
+    state.submit_proof_signed_expect_err(
+        &CLIENT,
+        b"job_signed",
+        b"proof_2",
+        &sig,
+        expiry,
+        "ed25519 verify error",
+    );
+
+    // Any relayer may submit a valid signature
+    state.submit_proof_signed(&CLIENT, b"job_signed", b"proof_1", &sig, expiry);
+    assert_eq!(state.query_agent_proof_nonce(1), 1);
+    let job = state.query_job_data(b"job_signed").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.proof, ManagedBuffer::<StaticApi>::from(b"proof_1"));
+    let proofs = state.query_job_proofs(b"job_signed", 0, 10);
+    assert_eq!(
+        proofs[0].submitter,
+        ManagedAddress::<StaticApi>::from(agent_key.verifying_key().to_bytes())
+    );
+
+    // Replay is rejected: the nonce moved on
+    state.submit_proof_signed_expect_err(
+        &CLIENT,
+        b"job_signed",
+        b"proof_1",
+        &sig,
+        expiry,
+        "ed25519 verify error",
+    );
+
+    // Expired signature
+    let sig = state.sign_proof(&agent_key, b"job_signed", b"proof_2", expiry);
+    state.world.current_block().block_timestamp_millis(5_001);
+    state.submit_proof_signed_expect_err(
+        &CLIENT,
+        b"job_signed",
+        b"proof_2",
+        &sig,
+        expiry,
+        "Signature has expired",
+    );
+}
+
+#[test]
+fn test_submit_proof_signed_invalid_public_key() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_signed", 1, None);
+
+    state.submit_proof_signed_expect_err(
+        &CLIENT,
+        b"job_signed",
+        b"proof_1",
+        &[0u8; 64],
+        TimestampMillis::new(u64::MAX),
+        "Agent public key is not a valid Ed25519 key",
+    );
+}
+
+// ============================================
+// 78. Employer-committed job requirements
+// ============================================
+
+#[test]
+fn test_job_requirements() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_with_requirements(
+        &CLIENT,
+        b"job_req",
+        1,
+        b"requirements_hash",
+        b"ipfs://requirements",
+    );
+    let (hash, uri) = state.query_job_requirements(b"job_req");
+    assert_eq!(hash, ManagedBuffer::<StaticApi>::from(b"requirements_hash"));
+    assert_eq!(
+        uri,
+        ManagedBuffer::<StaticApi>::from(b"ipfs://requirements")
+    );
+    let job = state.query_job_data(b"job_req").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::New);
+
+    // Plain init_job commits to nothing
+    state.init_job(&CLIENT, b"job_plain", 1, None);
+    let (hash, uri) = state.query_job_requirements(b"job_plain");
+    assert!(hash.is_empty());
+    assert!(uri.is_empty());
+
+    // Generated IDs and held payments can commit to requirements too
+    let created =
+        state.create_job_with_requirements(&CLIENT, 1, b"created_hash", b"ipfs://created");
+    let (hash, uri) = state.query_job_requirements(&created);
+    assert_eq!(hash, ManagedBuffer::<StaticApi>::from(b"created_hash"));
+    assert_eq!(uri, ManagedBuffer::<StaticApi>::from(b"ipfs://created"));
+
+    state.init_job_held_with_requirements(
+        &CLIENT,
+        b"job_held_req",
+        1,
+        b"held_hash",
+        b"ipfs://held",
+        1,
+        100,
+    );
+    let (hash, uri) = state.query_job_requirements(b"job_held_req");
+    assert_eq!(hash, ManagedBuffer::<StaticApi>::from(b"held_hash"));
+    assert_eq!(uri, ManagedBuffer::<StaticApi>::from(b"ipfs://held"));
+    assert!(state.query_held_payment(b"job_held_req").is_some());
+}
+
+// ============================================
+// 79. giveFeedbackSimple — rating is bound to the job's agent
+// ============================================
+
+#[test]
+fn test_give_feedback_simple_cannot_rate_other_agent() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"CheapAgent",
+        b"https://cheap.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+    state.register_agent(
+        &WORKER,
+        b"Competitor",
+        b"https://competitor.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    // Employer runs one cheap job with agent 1 and rates it
+    state.init_job(&CLIENT, b"cheap_job", 1, None);
+    state.submit_proof(&AGENT, b"cheap_job", b"proof");
+    state.accept_delivery(&CLIENT, b"cheap_job");
+    state.give_feedback_simple(&CLIENT, b"cheap_job", 0);
+
+    // The rating lands on the job's agent, never on the competitor
+    assert_eq!(state.query_total_jobs(1), 1);
+    assert_eq!(state.query_total_jobs(2), 0);
+    assert_eq!(
+        state.query_reputation_score(2),
+        BigUint::<StaticApi>::zero()
+    );
+
+    // A job with the competitor that was never delivered cannot be rated either
+    state.init_job(&CLIENT, b"unfinished_job", 2, None);
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"unfinished_job",
+        0,
+        "Feedback requires a verified job",
+    );
+    assert_eq!(state.query_total_jobs(2), 0);
+
+    // The agent owner cannot un-verify a job to keep the employer from rating it
+    state.init_job(&CLIENT, b"verified_job", 1, None);
+    state.submit_proof(&AGENT, b"verified_job", b"proof");
+    state.accept_delivery(&CLIENT, b"verified_job");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"verified_job",
+        &WORKER,
+        b"https://val.uri",
+        b"undo_hash",
+        "Job already verified",
+    );
+    assert!(state.query_is_job_verified(b"verified_job"));
+    state.give_feedback_simple(&CLIENT, b"verified_job", 40);
+    assert_eq!(state.query_total_jobs(1), 2);
+}
+
+// ============================================
+// 80. giveFeedbackSimple — bounded rating scale
+// ============================================
+
+#[test]
+fn test_rating_scale() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+    for job_id in [&b"scale_1"[..], b"scale_2"] {
+        state.init_job(&CLIENT, job_id, 1, None);
+        state.submit_proof(&AGENT, job_id, b"proof");
+        state.accept_delivery(&CLIENT, job_id);
+    }
+
+    // Default scale: 0-100, no decimals
+    let scale = state.query_rating_scale();
+    assert_eq!((scale.max_value, scale.decimals), (100, 0));
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"scale_1",
+        i64::MAX as u64,
+        "Rating is outside the rating scale",
+    );
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"scale_1",
+        101,
+        "Rating is outside the rating scale",
+    );
+    state.give_feedback_simple(&CLIENT, b"scale_1", 100);
+
+    // Owner switches to 0.00-100.00
+    state.set_rating_scale_expect_err(&CLIENT, 10_000, 2, "Endpoint can only be called by owner");
+    state.set_rating_scale_expect_err(
+        &OWNER_ADDRESS,
+        0,
+        2,
+        "Rating scale maximum must be greater than zero",
+    );
+    state.set_rating_scale_expect_err(&OWNER_ADDRESS, 10_000, 19, "Value decimals must be 0-18");
+    state.set_rating_scale(&OWNER_ADDRESS, 10_000, 2);
+    let scale = state.query_rating_scale();
+    assert_eq!((scale.max_value, scale.decimals), (10_000, 2));
+
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"scale_2",
+        10_001,
+        "Rating is outside the rating scale",
+    );
+    state.give_feedback_simple(&CLIENT, b"scale_2", 9_550);
+    assert_eq!(state.query_total_jobs(1), 2);
+}
+
+// ============================================
+// 81. Time-decayed reputation score
+// ============================================
+
+#[test]
+fn test_decayed_score() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+    let day_ms: u64 = 24 * 60 * 60 * 1000;
+    const WAD: u64 = 1_000_000_000_000_000_000;
+
+    assert_eq!(state.query_score_half_life().as_u64_millis(), 30 * day_ms);
+    state.set_score_half_life_expect_err(&CLIENT, day_ms, "Endpoint can only be called by owner");
+    state.set_score_half_life_expect_err(&OWNER_ADDRESS, 0, "Half-life must be greater than zero");
+    state.set_score_half_life(&OWNER_ADDRESS, day_ms);
+    assert_eq!(state.query_decayed_score(1), BigUint::<StaticApi>::zero());
+
+    for job_id in [&b"decay_1"[..], b"decay_2"] {
+        state.init_job(&CLIENT, job_id, 1, None);
+        state.submit_proof(&AGENT, job_id, b"proof");
+        state.accept_delivery(&CLIENT, job_id);
+    }
+
+    state.world.current_block().block_timestamp_millis(day_ms);
+    state.give_feedback_simple(&CLIENT, b"decay_1", 100);
+    assert_eq!(
+        state.query_decayed_score(1),
+        BigUint::<StaticApi>::from(100u64)
+    );
+    assert_eq!(
+        state.query_decayed_rating_weight(1),
+        BigUint::<StaticApi>::from(WAD)
+    );
+
+    // One half-life later the old rating weighs 0.5: (100 * 0.5 + 0) / 1.5 = 33
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(2 * day_ms);
+    state.give_feedback_simple(&CLIENT, b"decay_2", 0);
+    assert_eq!(
+        state.query_decayed_score(1),
+        BigUint::<StaticApi>::from(33u64)
+    );
+    assert_eq!(
+        state.query_reputation_score(1),
+        BigUint::<StaticApi>::from(50u64)
+    );
+    assert_eq!(
+        state.query_decayed_rating_weight(1),
+        BigUint::<StaticApi>::from(3 * WAD / 2)
+    );
+
+    // Without new ratings the score holds while its weight keeps decaying
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(3 * day_ms);
+    assert_eq!(
+        state.query_decayed_score(1),
+        BigUint::<StaticApi>::from(33u64)
+    );
+    assert_eq!(
+        state.query_decayed_rating_weight(1),
+        BigUint::<StaticApi>::from(3 * WAD / 4)
+    );
+}
+
+// ============================================
+// 82. Payment-weighted reputation
+// ============================================
+
+#[test]
+fn test_payment_weighted_score() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![
+            (1u32, 100u64, b"USDC-abcdef", 0u64),
+            (2u32, 900u64, b"USDC-abcdef", 0u64),
+            (3u32, 5_000u64, b"WRONG-abcdef", 0u64),
+        ],
+    );
+    const WAD: u64 = 1_000_000_000_000_000_000;
+    state.set_token_price(&OWNER_ADDRESS, &PAYMENT_TOKEN, WAD);
+
+    state.init_job_with_payment(&CLIENT, b"small_job", 1, 1, "USDC-abcdef", 0, 100);
+    state.init_job_with_payment(&CLIENT, b"large_job", 1, 2, "USDC-abcdef", 0, 900);
+    state.init_job_with_payment(&CLIENT, b"unpriced_job", 1, 3, "WRONG-abcdef", 0, 5_000);
+    state.init_job(&CLIENT, b"free_job", 1, None);
+    state.init_job_with_payment(&CLIENT, b"overpaid_job", 1, 1, "USDC-abcdef", 0, 900);
+    for job_id in [
+        &b"small_job"[..],
+        b"large_job",
+        b"unpriced_job",
+        b"free_job",
+        b"overpaid_job",
+    ] {
+        state.submit_proof(&AGENT, job_id, b"proof");
+        state.accept_delivery(&CLIENT, job_id);
+    }
+    let payment = state.query_job_payment(b"large_job").unwrap();
+    assert_eq!(
+        payment.amount.as_big_uint(),
+        &BigUint::<StaticApi>::from(900u64)
+    );
+    assert!(state.query_job_payment(b"free_job").is_none());
+    // Overpaying is recorded at the service price
+    let payment = state.query_job_payment(b"overpaid_job").unwrap();
+    assert_eq!(
+        payment.amount.as_big_uint(),
+        &BigUint::<StaticApi>::from(100u64)
+    );
+
+    // (100 * 100 + 0 * 900) / 1000 = 10, while the plain average is 50
+    state.give_feedback_simple(&CLIENT, b"small_job", 100);
+    state.give_feedback_simple(&CLIENT, b"large_job", 0);
+    assert_eq!(
+        state.query_reputation_score(1),
+        BigUint::<StaticApi>::from(50u64)
+    );
+    assert_eq!(
+        state.query_payment_weighted_score(1),
+        BigUint::<StaticApi>::from(10u64)
+    );
+    assert_eq!(
+        state.query_payment_weight_total(1),
+        BigUint::<StaticApi>::from(1_000u64) * WAD
+    );
+
+    // Unpriced and unpaid jobs only move the unweighted score (300 / 4)
+    state.give_feedback_simple(&CLIENT, b"unpriced_job", 100);
+    state.give_feedback_simple(&CLIENT, b"free_job", 100);
+    assert_eq!(
+        state.query_reputation_score(1),
+        BigUint::<StaticApi>::from(75u64)
+    );
+    assert_eq!(
+        state.query_payment_weighted_score(1),
+        BigUint::<StaticApi>::from(10u64)
+    );
+
+    // The overpaid job weighs its 100 price, not the 900 sent: 20000 / 1100 = 18
+    state.give_feedback_simple(&CLIENT, b"overpaid_job", 100);
+    assert_eq!(
+        state.query_payment_weighted_score(1),
+        BigUint::<StaticApi>::from(18u64)
+    );
+    assert_eq!(
+        state.query_payment_weight_total(1),
+        BigUint::<StaticApi>::from(1_100u64) * WAD
+    );
+}
+
+// ============================================
+// 83. Per-tag and per-service reputation breakdown
+// ============================================
+
+#[test]
+fn test_score_by_tag_and_service() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![
+            (1u32, 10u64, b"USDC-abcdef", 0u64),
+            (2u32, 10u64, b"USDC-abcdef", 0u64),
+        ],
+    );
+    // value / 10^decimals as an 18-decimal fixed-point number
+    let normalized = |value: i64, decimals: u32| {
+        BigInt::<StaticApi>::from(value)
+            * BigInt::from(BigUint::<StaticApi>::from(10u64).pow(18 - decimals))
+    };
+
+    // ERC-8004 feedback: values are normalized to 18 decimals per tag
+    state.give_feedback(&CLIENT, 1, 80, 0, b"quality", b"speed");
+    state.give_feedback(&CLIENT, 1, 4050, 2, b"quality", b"quality");
+    state.give_feedback(&WORKER, 1, -5, 0, b"speed", b"");
+    let (count, sum, average) = state.query_score_by_tag(1, b"quality");
+    assert_eq!(count, 2);
+    assert_eq!(sum, normalized(12_050, 2));
+    assert_eq!(average, normalized(6_025, 2));
+    let (count, sum, _) = state.query_score_by_tag(1, b"speed");
+    assert_eq!(count, 2);
+    assert_eq!(sum, normalized(75, 0));
+
+    // Revoked feedback leaves the aggregates
+    state.revoke_feedback(&CLIENT, 1, 1);
+    let (count, sum, average) = state.query_score_by_tag(1, b"speed");
+    assert_eq!(count, 1);
+    assert_eq!(sum, normalized(-5, 0));
+    assert_eq!(average, normalized(-5, 0));
+    let (count, _, average) = state.query_score_by_tag(1, b"quality");
+    assert_eq!(count, 1);
+    assert_eq!(average, normalized(4_050, 2));
+    assert_eq!(state.query_score_by_tag(1, b"unknown").0, 0);
+
+    // Simple feedback aggregates per service of the rated job
+    state.init_job_with_payment(&CLIENT, b"svc1_a", 1, 1, "USDC-abcdef", 0, 10);
+    state.init_job_with_payment(&CLIENT, b"svc1_b", 1, 1, "USDC-abcdef", 0, 10);
+    state.init_job_with_payment(&CLIENT, b"svc2_a", 1, 2, "USDC-abcdef", 0, 10);
+    state.init_job(&CLIENT, b"no_svc", 1, None);
+    for job_id in [&b"svc1_a"[..], b"svc1_b", b"svc2_a", b"no_svc"] {
+        state.submit_proof(&AGENT, job_id, b"proof");
+        state.accept_delivery(&CLIENT, job_id);
+    }
+    state.give_feedback_simple(&CLIENT, b"svc1_a", 90);
+    state.give_feedback_simple(&CLIENT, b"svc1_b", 71);
+    state.give_feedback_simple(&CLIENT, b"svc2_a", 20);
+    state.give_feedback_simple(&CLIENT, b"no_svc", 100);
+
+    let (count, sum, average) = state.query_score_by_service(1, 1);
+    assert_eq!(count, 2);
+    assert_eq!(sum, BigUint::<StaticApi>::from(161u64));
+    assert_eq!(average, BigUint::<StaticApi>::from(80u64));
+    let (count, _, average) = state.query_score_by_service(1, 2);
+    assert_eq!(count, 1);
+    assert_eq!(average, BigUint::<StaticApi>::from(20u64));
+    assert_eq!(state.query_total_jobs(1), 4);
+}
+
+// ============================================
+// 84. ERC-8004 getSummary / readAllFeedback
+// ============================================
+
+#[test]
+fn test_get_summary_and_read_all_feedback() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+    let normalized = |value: i64, decimals: u32| {
+        BigInt::<StaticApi>::from(value)
+            * BigInt::from(BigUint::<StaticApi>::from(10u64).pow(18 - decimals))
+    };
+
+    state.give_feedback(&CLIENT, 1, 80, 0, b"quality", b"speed");
+    state.give_feedback(&CLIENT, 1, 60, 0, b"quality", b"");
+    state.give_feedback(&WORKER, 1, -10, 0, b"speed", b"quality");
+    state.give_feedback(&WORKER, 1, 4050, 2, b"quality", b"speed");
+    assert_eq!(state.query_feedback_count(1), 4);
+
+    // No filters: every non-revoked entry
+    let (count, value, decimals) = state.query_summary(1, b"", b"", &[]);
+    assert_eq!(count, 4);
+    assert_eq!(decimals, 18);
+    assert_eq!(value, normalized(17_050, 2) / BigInt::from(4i64));
+
+    // Tag filters match tag1 and tag2 positionally
+    let (count, value, _) = state.query_summary(1, b"quality", b"", &[]);
+    assert_eq!(count, 3);
+    assert_eq!(value, normalized(18_050, 2) / BigInt::from(3i64));
+    let (count, value, _) = state.query_summary(1, b"quality", b"speed", &[]);
+    assert_eq!(count, 2);
+    assert_eq!(value, normalized(12_050, 2) / BigInt::from(2i64));
+    assert_eq!(state.query_summary(1, b"", b"quality", &[]).0, 1);
+
+    // Client filters sum the per-client buckets
+    let (count, value, _) = state.query_summary(1, b"", b"", &[&WORKER]);
+    assert_eq!(count, 2);
+    assert_eq!(value, normalized(3_050, 2) / BigInt::from(2i64));
+    let (count, _, _) = state.query_summary(1, b"quality", b"", &[&CLIENT, &WORKER]);
+    assert_eq!(count, 3);
+    let (count, value, _) = state.query_summary(1, b"", b"", &[&AGENT_OWNER]);
+    assert_eq!(count, 0);
+    assert_eq!(value, BigInt::zero());
+
+    // Revoked feedback leaves the summary
+    state.revoke_feedback(&CLIENT, 1, 1);
+    let (count, value, _) = state.query_summary(1, b"quality", b"speed", &[]);
+    assert_eq!(count, 1);
+    assert_eq!(value, normalized(4_050, 2));
+    assert_eq!(state.query_summary(1, b"", b"", &[&CLIENT]).0, 1);
+
+    // readAllFeedback pages over the log in insertion order
+    let (cursor, entries) = state.query_all_feedback(1, 0, 2, b"", b"", false, &[]);
+    assert_eq!(cursor, 2);
+    assert_eq!(entries.len(), 1);
+    let entry = entries.get(0);
+    assert_eq!(entry.client, CLIENT.to_managed_address());
+    assert_eq!(entry.feedback_index, 2);
+    assert_eq!(entry.value, 60);
+
+    let (cursor, entries) = state.query_all_feedback(1, 0, 2, b"", b"", true, &[]);
+    assert_eq!(cursor, 2);
+    assert_eq!(entries.len(), 2);
+    assert!(entries.get(0).is_revoked);
+
+    let (cursor, entries) = state.query_all_feedback(1, cursor, 10, b"", b"", true, &[]);
+    assert_eq!(cursor, 4);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries.get(1).value, 4050);
+    assert_eq!(entries.get(1).value_decimals, 2);
+
+    // Client and tag filters
+    let (_, entries) = state.query_all_feedback(1, 0, 10, b"", b"", true, &[&WORKER]);
+    assert_eq!(entries.len(), 2);
+    let (_, entries) = state.query_all_feedback(1, 0, 10, b"quality", b"speed", true, &[]);
+    assert_eq!(entries.len(), 2);
+    let (_, entries) = state.query_all_feedback(1, 0, 10, b"speed", b"", false, &[&CLIENT]);
+    assert!(entries.is_empty());
+
+    // Past the end
+    let (cursor, entries) = state.query_all_feedback(1, 10, 5, b"", b"", true, &[]);
+    assert_eq!(cursor, 4);
+    assert!(entries.is_empty());
+}
+
+// ============================================
+// 85. ERC-8004 appendResponse — append-only per feedback
+// ============================================
+
+#[test]
+fn test_append_feedback_response() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+    state.give_feedback(&CLIENT, 1, 20, 0, b"quality", b"");
+    state.give_feedback(&CLIENT, 1, 90, 0, b"quality", b"");
+
+    // Anyone can respond, nobody overwrites
+    state.append_feedback_response(&AGENT_OWNER, 1, &CLIENT, 1, b"https://refund.uri", b"h1");
+    state.append_feedback_response(&WORKER, 1, &CLIENT, 1, b"https://spam.uri", b"");
+    assert_eq!(state.query_response_count(1, &CLIENT, 1), 2);
+    assert_eq!(state.query_response_count(1, &CLIENT, 2), 0);
+
+    let (cursor, responses) = state.query_responses(1, &CLIENT, 1, 0, 1);
+    assert_eq!(cursor, 1);
+    let first = responses.get(0);
+    assert_eq!(first.responder, AGENT_OWNER.to_managed_address());
+    assert_eq!(
+        first.response_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://refund.uri")
+    );
+    assert_eq!(first.response_hash, ManagedBuffer::<StaticApi>::from(b"h1"));
+    let (cursor, responses) = state.query_responses(1, &CLIENT, 1, cursor, 10);
+    assert_eq!(cursor, 2);
+    assert_eq!(responses.get(0).responder, WORKER.to_managed_address());
+
+    // Revoked feedback can still be answered; unknown feedback cannot
+    state.revoke_feedback(&CLIENT, 1, 1);
+    state.append_feedback_response(&CLIENT, 1, &CLIENT, 1, b"https://retracted.uri", b"");
+    assert_eq!(state.query_response_count(1, &CLIENT, 1), 3);
+    state.append_feedback_response_expect_err(&AGENT_OWNER, 1, &CLIENT, 3, "Feedback not found");
+    state.append_feedback_response_expect_err(&AGENT_OWNER, 1, &WORKER, 1, "Feedback not found");
+
+    // Legacy endpoint: third parties are logged but cannot replace the job parties' response
+    state.init_job(&CLIENT, b"job-resp", 1, None);
+    state.append_response(&AGENT_OWNER, b"job-resp", b"https://refund.uri");
+    state.append_response(&WORKER, b"job-resp", b"https://erase.uri");
+    assert_eq!(
+        state.query_agent_response(b"job-resp"),
+        ManagedBuffer::<StaticApi>::from(b"https://refund.uri")
+    );
+    let (cursor, responses) = state.query_job_responses(b"job-resp", 0, 10);
+    assert_eq!(cursor, 2);
+    assert_eq!(responses.get(0).responder, AGENT_OWNER.to_managed_address());
+    assert_eq!(responses.get(1).responder, WORKER.to_managed_address());
+    assert_eq!(
+        responses.get(1).response_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://erase.uri")
+    );
+}
+
+// ============================================
+// 86. ERC-8004 feedbackAuth — agent-signed feedback authorization
+// ============================================
+
+#[test]
+fn test_feedback_auth() {
+    let mut state = AgentTestState::new();
+    let agent_key = SigningKey::from_bytes(&[7u8; 32]);
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        &agent_key.verifying_key().to_bytes(),
+        vec![],
+        vec![],
+    );
+    state.world.current_block().block_timestamp_millis(1_000);
+    let expiry = TimestampMillis::new(5_000);
+
+    // Optional by default, but verified whenever given
+    state.give_feedback_with_auth(&CLIENT, 1, 50, None);
+    let other_key = SigningKey::from_bytes(&[9u8; 32]);
+    let forged = state.sign_feedback_auth(&other_key, 1, &CLIENT, 5, expiry);
+    state.give_feedback_with_auth_expect_err(&CLIENT, 1, 60, Some(forged), "ed25519 verify error");
+
+    // Only the agent owner can require it
+    state.set_feedback_auth_required_expect_err(
+        &CLIENT,
+        1,
+        true,
+        "Only the agent owner can perform this action",
+    );
+    state.set_feedback_auth_required_expect_err(&AGENT_OWNER, 2, true, "Agent not found");
+    state.set_feedback_auth_required(&AGENT_OWNER, 1, true);
+    assert!(state.query_feedback_auth_required(1));
+    state.give_feedback_with_auth_expect_err(
+        &CLIENT,
+        1,
+        60,
+        None,
+        "Agent requires a feedback authorization",
+    );
+
+    // Authorization is bound to the client and the index limit
+    let auth = state.sign_feedback_auth(&agent_key, 1, &CLIENT, 3, expiry);
+    state.give_feedback_with_auth(&CLIENT, 1, 60, Some(auth));
+    let auth = state.sign_feedback_auth(&agent_key, 1, &CLIENT, 3, expiry);
+    state.give_feedback_with_auth(&CLIENT, 1, 70, Some(auth));
+    let auth = state.sign_feedback_auth(&agent_key, 1, &CLIENT, 3, expiry);
+    state.give_feedback_with_auth_expect_err(
+        &CLIENT,
+        1,
+        80,
+        Some(auth),
+        "Feedback index exceeds the authorized limit",
+    );
+    assert_eq!(state.query_feedback_count(1), 3);
+
+    let auth = state.sign_feedback_auth(&agent_key, 1, &CLIENT, 3, expiry);
+    state.give_feedback_with_auth_expect_err(&WORKER, 1, 80, Some(auth), "ed25519 verify error");
+    let auth = state.sign_feedback_auth(&agent_key, 1, &WORKER, 1, expiry);
+    state.give_feedback_with_auth(&WORKER, 1, 80, Some(auth));
+
+    // Expired authorization
+    state.world.current_block().block_timestamp_millis(5_001);
+    let auth = state.sign_feedback_auth(&agent_key, 1, &CLIENT, 10, expiry);
+    state.give_feedback_with_auth_expect_err(
+        &CLIENT,
+        1,
+        80,
+        Some(auth),
+        "Feedback authorization has expired",
+    );
+
+    // Turning the requirement off restores open feedback
+    state.set_feedback_auth_required(&AGENT_OWNER, 1, false);
+    state.give_feedback_with_auth(&CLIENT, 1, 90, None);
+    assert_eq!(state.query_feedback_count(1), 5);
+}
+
+// ============================================
+// 87. Correction window for simple feedback
+// ============================================
+
+#[test]
+fn test_correct_feedback_simple() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    const WAD: u64 = 1_000_000_000_000_000_000;
+    const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+    state.set_token_price(&OWNER_ADDRESS, &PAYMENT_TOKEN, WAD);
+    state.world.current_block().block_timestamp_millis(1_000);
+
+    state.init_job_with_payment(&CLIENT, b"job_a", 1, 1, "USDC-abcdef", 0, 100);
+    state.init_job_with_payment(&CLIENT, b"job_b", 1, 1, "USDC-abcdef", 0, 100);
+    state.init_job(&CLIENT, b"job_c", 1, None);
+    state.init_job(&CLIENT, b"job_d", 1, None);
+    for job_id in [&b"job_a"[..], b"job_b", b"job_c", b"job_d"] {
+        state.submit_proof(&AGENT, job_id, b"proof");
+        state.accept_delivery(&CLIENT, job_id);
+    }
+    state.give_feedback_simple(&CLIENT, b"job_a", 90);
+    state.give_feedback_simple(&CLIENT, b"job_b", 30);
+    state.give_feedback_simple(&CLIENT, b"job_c", 60);
+    assert_eq!(
+        state.query_reputation_score(1),
+        BigUint::<StaticApi>::from(60u64)
+    );
+
+    // Only the employer, only rated jobs, only within the scale
+    state.correct_feedback_simple_expect_err(
+        &WORKER,
+        b"job_b",
+        90,
+        "Only the employer can provide feedback",
+    );
+    state.correct_feedback_simple_expect_err(&CLIENT, b"job_d", 90, "Feedback not found");
+    state.correct_feedback_simple_expect_err(
+        &CLIENT,
+        b"job_b",
+        101,
+        "Rating is outside the rating scale",
+    );
+
+    // Every score moves as if 90 had been given instead of 30
+    state.correct_feedback_simple(&CLIENT, b"job_b", 90);
+    assert_eq!(
+        state.query_reputation_score(1),
+        BigUint::<StaticApi>::from(80u64)
+    );
+    assert_eq!(state.query_total_jobs(1), 3);
+    assert_eq!(
+        state.query_decayed_score(1),
+        BigUint::<StaticApi>::from(80u64)
+    );
+    assert_eq!(
+        state.query_payment_weighted_score(1),
+        BigUint::<StaticApi>::from(90u64)
+    );
+    let (count, sum, _) = state.query_score_by_service(1, 1);
+    assert_eq!(count, 2);
+    assert_eq!(sum, BigUint::<StaticApi>::from(180u64));
+
+    // The window runs from the original rating, not from the last correction
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(1_000 + DAY_MS);
+    state.correct_feedback_simple(&CLIENT, b"job_c", 0);
+    assert_eq!(
+        state.query_reputation_score(1),
+        BigUint::<StaticApi>::from(60u64)
+    );
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(1_000 + DAY_MS + 1);
+    state.correct_feedback_simple_expect_err(
+        &CLIENT,
+        b"job_c",
+        60,
+        "Feedback correction window has closed",
+    );
+
+    // The owner can shorten the window
+    state.set_feedback_correction_window_expect_err(
+        &OWNER_ADDRESS,
+        0,
+        "Correction window must be greater than zero",
+    );
+    state.set_feedback_correction_window(&OWNER_ADDRESS, 10);
+    state.give_feedback_simple(&CLIENT, b"job_d", 100);
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(1_000 + DAY_MS + 12);
+    state.correct_feedback_simple_expect_err(
+        &CLIENT,
+        b"job_d",
+        0,
+        "Feedback correction window has closed",
+    );
+    assert_eq!(
+        state.query_reputation_score(1),
+        BigUint::<StaticApi>::from(70u64)
+    );
+}
+
+// ============================================
+// 88. Employer reputation — agents rate employers
+// ============================================
+
+#[test]
+fn test_rate_employer() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.register_agent(
+        &WORKER,
+        b"OtherAgent",
+        b"https://other.example.com",
+        VALIDATOR.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    state.init_job(&CLIENT, b"job_ok", 1, None);
+    state.init_job_held(&CLIENT, b"job_bad", 1, 1, 100);
+    state.init_job(&CLIENT, b"job_open", 1, None);
+    state.init_job(&AGENT_OWNER, b"job_other", 2, None);
+    state.submit_proof(&AGENT, b"job_ok", b"proof");
+    state.accept_delivery(&CLIENT, b"job_ok");
+    state.submit_proof(&AGENT, b"job_open", b"proof");
+
+    // A job the employer won in a dispute is completed as well
+    state.add_arbiters(vec![&ARBITER_1, &ARBITER_2]);
+    state.set_dispute_quorum(2);
+    state.submit_proof(&AGENT, b"job_bad", b"proof");
+    state.open_dispute(&CLIENT, b"job_bad", b"ipfs://reason");
+    state.vote_dispute(&ARBITER_1, b"job_bad", false);
+    state.vote_dispute(&ARBITER_2, b"job_bad", false);
+
+    // Only the owner of the job's agent, only once the job has an outcome
+    state.rate_employer_expect_err(
+        &WORKER,
+        b"job_ok",
+        90,
+        "Only the agent owner can perform this action",
+    );
+    state.rate_employer_expect_err(
+        &CLIENT,
+        b"job_ok",
+        90,
+        "Only the agent owner can perform this action",
+    );
+    state.rate_employer_expect_err(
+        &AGENT_OWNER,
+        b"job_open",
+        90,
+        "Employer rating requires a completed job",
+    );
+    state.rate_employer_expect_err(&AGENT_OWNER, b"job_none", 90, "Job not found");
+    state.rate_employer_expect_err(
+        &AGENT_OWNER,
+        b"job_ok",
+        101,
+        "Rating is outside the rating scale",
+    );
+
+    state.rate_employer(&AGENT_OWNER, b"job_ok", 90);
+    assert!(state.query_has_rated_employer(b"job_ok"));
+    state.rate_employer_expect_err(
+        &AGENT_OWNER,
+        b"job_ok",
+        10,
+        "Employer already rated for this job",
+    );
+    state.rate_employer(&AGENT_OWNER, b"job_bad", 15);
+    assert_eq!(
+        state.query_employer_reputation_score(&CLIENT),
+        BigUint::<StaticApi>::from(52u64)
+    );
+    assert_eq!(state.query_employer_total_jobs(&CLIENT), 2);
+
+    // Scores are per employer (here agent 1's owner hiring agent 2) and leave agent scores alone
+    state.submit_proof(&VALIDATOR, b"job_other", b"proof");
+    state.accept_delivery(&AGENT_OWNER, b"job_other");
+    state.rate_employer(&WORKER, b"job_other", 70);
+    assert_eq!(
+        state.query_employer_reputation_score(&AGENT_OWNER),
+        BigUint::<StaticApi>::from(70u64)
+    );
+    assert_eq!(state.query_employer_total_jobs(&CLIENT), 2);
+    assert_eq!(state.query_total_jobs(1), 0);
+    assert!(!state.query_has_rated_employer(b"job_open"));
+
+    // A cancelled job can be rated once the agent delivered something
+    state.init_job_held(&CLIENT, b"job_refunded", 1, 1, 100);
+    state.init_job_held(&CLIENT, b"job_withdrawn", 1, 1, 100);
+    state.submit_proof(&AGENT, b"job_refunded", b"proof");
+    state.cancel_job(&CLIENT, b"job_withdrawn");
+    let eight_days_ms: u64 = 8 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(eight_days_ms);
+    state.claim_refund(&CLIENT, b"job_refunded");
+
+    state.rate_employer_expect_err(
+        &AGENT_OWNER,
+        b"job_withdrawn",
+        90,
+        "Employer rating requires a completed job",
+    );
+    state.rate_employer(&AGENT_OWNER, b"job_refunded", 0);
+    assert_eq!(
+        state.query_employer_reputation_score(&CLIENT),
+        BigUint::<StaticApi>::from(35u64)
+    );
+    assert_eq!(state.query_employer_total_jobs(&CLIENT), 3);
+}
+
+// ============================================
+// 89. Disputes — deadline, default outcome and panel snapshot
+// ============================================
+
+#[test]
+fn test_dispute_deadline_and_panel() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.add_arbiters(vec![&ARBITER_1, &ARBITER_2, &CLIENT]);
+    state.set_dispute_quorum(2);
+    state.set_dispute_bond(10);
+    let day_ms: u64 = 24 * 60 * 60 * 1000;
+    state.set_dispute_period(2 * day_ms);
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job_held(&CLIENT, b"stuck_job", 1, 1, 100);
+    state.submit_proof(&AGENT, b"stuck_job", b"proof");
+    state.open_dispute_with_bond(&CLIENT, b"stuck_job", b"ipfs://reason", 10);
+    let dispute = state.query_dispute(b"stuck_job").unwrap();
+    assert_eq!(dispute.quorum, 2);
+    assert_eq!(dispute.deadline.as_u64_millis(), 2 * day_ms);
+
+    // The employer sits on the panel but cannot judge their own job
+    state.vote_dispute_expect_err(
+        &CLIENT,
+        b"stuck_job",
+        false,
+        "Job parties cannot vote on their own dispute",
+    );
+
+    // Later panel and quorum changes do not apply to an open dispute
+    state.remove_arbiters(vec![&ARBITER_2]);
+    state.add_arbiters(vec![&ARBITER_3]);
+    state.set_dispute_quorum(1);
+    state.vote_dispute_expect_err(
+        &ARBITER_3,
+        b"stuck_job",
+        true,
+        "Only a registered arbiter can vote",
+    );
+    state.vote_dispute(&ARBITER_1, b"stuck_job", true);
+    assert_eq!(
+        state
+            .query_job_data(b"stuck_job")
+            .into_option()
+            .unwrap()
+            .status,
+        JobStatus::Disputed
+    );
+
+    // No decision by the deadline: anyone applies the default outcome
+    state.expire_dispute_expect_err(
+        &WORKER,
+        b"stuck_job",
+        "Dispute voting period has not ended yet",
+    );
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(2 * day_ms + 1);
+    state.vote_dispute_expect_err(
+        &ARBITER_2,
+        b"stuck_job",
+        true,
+        "Dispute voting period has ended",
+    );
+    state.expire_dispute(&WORKER, b"stuck_job");
+
+    let job = state.query_job_data(b"stuck_job").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Cancelled);
+    assert_eq!(
+        state.query_dispute(b"stuck_job").unwrap().status,
+        DisputeStatus::Expired
+    );
+    assert!(state.query_held_payment(b"stuck_job").is_none());
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+    state.expire_dispute_expect_err(&WORKER, b"stuck_job", "Dispute is not open");
+}
+
+// ============================================
+// 90. Clean Old Jobs — jobs awaiting an employer rating are kept
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_keeps_jobs_awaiting_employer_rating() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.set_validation_reputation_address(REPUTATION_SC_ADDRESS.to_managed_address());
+    state.add_arbiters(vec![&ARBITER_1, &ARBITER_2]);
+    state.set_dispute_quorum(2);
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job_held(&CLIENT, b"job_rejected", 1, 1, 100);
+    state.init_job_held(&CLIENT, b"job_refunded", 1, 1, 100);
+    state.init_job_held(&CLIENT, b"job_withdrawn", 1, 1, 100);
+    state.submit_proof(&AGENT, b"job_rejected", b"proof");
+    state.submit_proof(&AGENT, b"job_refunded", b"proof");
+    state.cancel_job(&CLIENT, b"job_withdrawn");
+    state.open_dispute(&CLIENT, b"job_rejected", b"ipfs://reason");
+    state.vote_dispute(&ARBITER_1, b"job_rejected", false);
+    state.vote_dispute(&ARBITER_2, b"job_rejected", false);
+
+    let day_ms: u64 = 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(8 * day_ms);
+    state.claim_refund(&CLIENT, b"job_refunded");
+
+    // Rejected and delivered-then-cancelled jobs wait for the agent owner's rating;
+    // a job cancelled before any delivery cannot be rated and goes right away
+    state.clean_old_jobs(vec![b"job_rejected", b"job_refunded", b"job_withdrawn"]);
+    assert!(state.query_job_data(b"job_rejected").is_some());
+    assert!(state.query_job_data(b"job_refunded").is_some());
+    assert!(state.query_job_data(b"job_withdrawn").is_none());
+
+    state.rate_employer(&AGENT_OWNER, b"job_rejected", 20);
+    state.clean_old_jobs(vec![b"job_rejected", b"job_refunded"]);
+    assert!(state.query_job_data(b"job_rejected").is_none());
+    assert!(state.query_job_data(b"job_refunded").is_some());
+
+    // Unrated jobs are only kept for the feedback window after their last activity
+    state.set_feedback_window(10 * day_ms);
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(10 * day_ms);
+    state.clean_old_jobs(vec![b"job_refunded"]);
+    assert!(state.query_job_data(b"job_refunded").is_some());
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(10 * day_ms + 1);
+    state.clean_old_jobs(vec![b"job_refunded"]);
+    assert!(state.query_job_data(b"job_refunded").is_none());
+}
+
+// ============================================
+// 91. Validation — held payments need an employer-approved validator
+// ============================================
+
+#[test]
+fn test_validation_cannot_release_held_payment_without_approval() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.init_job_held(&CLIENT, b"held_job", 1, 1, 100);
+
+    // No validation before delivery
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"held_job",
+        &AGENT_OWNER,
+        b"https://val.uri",
+        b"self_hash",
+        "Job has no delivered proof",
+    );
+
+    // The agent owner cannot name themselves (or any validator) the employer did not approve
+    state.submit_proof(&AGENT, b"held_job", b"proof");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"held_job",
+        &AGENT_OWNER,
+        b"https://val.uri",
+        b"self_hash",
+        "Validator was not approved by the employer",
+    );
+    state.approve_validator_expect_err(
+        &AGENT_OWNER,
+        b"held_job",
+        &AGENT_OWNER,
+        "Only the employer can call this",
+    );
+
+    // A failing score from the approved validator does not verify the job
+    state.approve_validator(&CLIENT, b"held_job", &VALIDATOR);
+    state.validation_request(
+        &AGENT_OWNER,
+        b"held_job",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"held_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"held_hash",
+        0,
+        b"https://resp.uri",
+        b"held_resp",
+        b"quality",
+    );
+    assert!(!state.query_is_job_verified(b"held_job"));
+    assert!(state.query_held_payment(b"held_job").is_some());
+
+    // A passing revision verifies it and releases the payment
+    state.validation_response(
+        &VALIDATOR,
+        b"held_hash",
+        80,
+        b"https://resp.uri",
+        b"held_resp_2",
+        b"quality",
+    );
+    assert!(state.query_is_job_verified(b"held_job"));
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+}
+
+// ============================================
+// 92. revokeFeedback — feedback from before the aggregates existed
+// ============================================
+
+#[test]
+fn test_revoke_feedback_given_before_aggregates() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![],
+    );
+
+    // Feedback stored by the previous contract version was never aggregated
+    state.seed_legacy_feedback(&CLIENT, 1, 40, b"quality");
+    state.give_feedback(&CLIENT, 1, 80, 0, b"quality", b"");
+    state.give_feedback(&WORKER, 1, 60, 0, b"quality", b"");
+    let wad = BigInt::<StaticApi>::from(1_000_000_000_000_000_000i64);
+    let (count, sum, _) = state.query_score_by_tag(1, b"quality");
+    assert_eq!(count, 2);
+    assert_eq!(sum, BigInt::<StaticApi>::from(140i64) * &wad);
+
+    // Revoking it leaves the aggregates alone instead of underflowing them
+    state.revoke_feedback(&CLIENT, 1, 1);
+    let (count, sum, _) = state.query_score_by_tag(1, b"quality");
+    assert_eq!(count, 2);
+    assert_eq!(sum, BigInt::<StaticApi>::from(140i64) * &wad);
+    let (count, average, _) = state.query_summary(1, b"", b"", &[]);
+    assert_eq!(count, 2);
+    assert_eq!(average, BigInt::<StaticApi>::from(70i64) * &wad);
+    let (count, _, _) = state.query_summary(1, b"", b"", &[&CLIENT]);
+    assert_eq!(count, 1);
+
+    // Feedback given after the upgrade is still removed on revoke
+    state.revoke_feedback(&CLIENT, 1, 2);
+    let (count, sum, _) = state.query_score_by_tag(1, b"quality");
+    assert_eq!(count, 1);
+    assert_eq!(sum, BigInt::<StaticApi>::from(60i64) * &wad);
+    let (count, _, _) = state.query_summary(1, b"", b"", &[&CLIENT]);
+    assert_eq!(count, 0);
+}
+
+// ============================================
+// 93. Disputes — job parties are left off the panel
+// ============================================
+
+#[test]
+fn test_dispute_panel_excludes_parties() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        AGENT.to_address().as_bytes(),
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.add_arbiters(vec![&ARBITER_1, &CLIENT, &AGENT_OWNER]);
+    state.set_dispute_quorum(2);
+
+    state.init_job_held(&CLIENT, b"held_job", 1, 1, 100);
+    state.submit_proof(&AGENT, b"held_job", b"proof");
+
+    // Three arbiters, but only one who is not a party: the quorum could never be reached
+    state.open_dispute_expect_err(
+        &CLIENT,
+        b"held_job",
+        b"ipfs://reason",
+        "Not enough arbiters for the dispute quorum",
+    );
+
+    state.add_arbiters(vec![&ARBITER_2]);
+    state.open_dispute(&CLIENT, b"held_job", b"ipfs://reason");
+    state.vote_dispute_expect_err(
+        &AGENT_OWNER,
+        b"held_job",
+        true,
+        "Job parties cannot vote on their own dispute",
+    );
+
+    // The two independent arbiters decide the dispute
+    state.vote_dispute(&ARBITER_1, b"held_job", false);
+    state.vote_dispute(&ARBITER_2, b"held_job", false);
+    assert_eq!(
+        state.query_dispute(b"held_job").unwrap().status,
+        DisputeStatus::ResolvedForEmployer
+    );
+    let job = state.query_job_data(b"held_job").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Rejected);
+}
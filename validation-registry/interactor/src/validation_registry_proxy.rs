@@ -103,6 +103,229 @@ where
             .original_result()
     }
 
+    /// Like `init_job`, but the employer commits to the job's requirements: the hash (and 
+    /// where to fetch the document) are stored on the job for validators to check proofs 
+    /// against. 
+    pub fn init_job_with_requirements<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<OptionalValue<u32>>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+        requirements_hash: Arg2,
+        requirements_uri: Arg3,
+        service_id: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("init_job_with_requirements")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .argument(&requirements_hash)
+            .argument(&requirements_uri)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    /// Batch variant of `init_job`. Payments are matched in order to the jobs that name a 
+    /// service: the first payment pays the first job with a `service_id`, and so on. Jobs 
+    /// without a service take no payment. The number of payments must match exactly. 
+    pub fn init_jobs<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, JobInput<Env::Api>>>,
+    >(
+        self,
+        jobs: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("init_jobs")
+            .argument(&jobs)
+            .original_result()
+    }
+
+    /// Same as `init_job`, but the job ID is generated on-chain from the caller's address, 
+    /// a per-employer nonce and the block nonce. Generated IDs cannot be front-run: if an 
+    /// ID is already taken, the next nonce is used. Returns the new job ID. 
+    pub fn create_job<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<u32>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("create_job")
+            .argument(&agent_nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    /// `create_job` with a requirements commitment, as in `init_job_with_requirements`. 
+    pub fn create_job_with_requirements<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<u32>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        requirements_hash: Arg1,
+        requirements_uri: Arg2,
+        service_id: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("create_job_with_requirements")
+            .argument(&agent_nonce)
+            .argument(&requirements_hash)
+            .argument(&requirements_uri)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    /// Protocol-held payment mode: like `init_job` with a service, but the payment stays 
+    /// in the contract until the job is verified. Until then the employer can `cancel_job` 
+    /// (before any proof) or, once the refund timeout has passed, `claim_refund`. 
+    pub fn init_job_held<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u32>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+        service_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("init_job_held")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    /// `init_job_held` with a requirements commitment, as in `init_job_with_requirements`. 
+    pub fn init_job_held_with_requirements<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<u32>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+        requirements_hash: Arg2,
+        requirements_uri: Arg3,
+        service_id: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("init_job_held_with_requirements")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .argument(&requirements_hash)
+            .argument(&requirements_uri)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    /// Employer cancels a held-payment job before the agent submitted a proof and gets 
+    /// the payment back. Jobs paid directly cannot be cancelled: the agent owner already 
+    /// has the payment. 
+    pub fn cancel_job<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_job")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Employer reclaims a held payment for a job that was not verified within the 
+    /// refund timeout, counted from the job's latest activity. The job is cancelled. 
+    pub fn claim_refund<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_refund")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Employer verifies the delivered job directly, without a validator. 
+    pub fn accept_delivery<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("accept_delivery")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Employer opts the job into optimistic verification: once a proof is submitted, 
+    /// anyone can `finalize_job` after `challenge_window` unless the employer challenges. 
+    /// Only possible before the agent delivers, with a window of at most 
+    /// `MAX_CHALLENGE_WINDOW`. 
+    pub fn enable_optimistic_verification<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<DurationMillis>,
+    >(
+        self,
+        job_id: Arg0,
+        challenge_window: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enable_optimistic_verification")
+            .argument(&job_id)
+            .argument(&challenge_window)
+            .original_result()
+    }
+
+    /// Employer disputes an optimistic delivery within the challenge window. The job then 
+    /// needs `accept_delivery` or a validator response to be verified. 
+    pub fn challenge_delivery<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("challenge_delivery")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Verifies an optimistic job whose challenge window has passed without a challenge. 
+    /// Callable by anyone. 
+    pub fn finalize_job<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("finalize_job")
+            .argument(&job_id)
+            .original_result()
+    }
+
     pub fn submit_proof<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -119,6 +342,71 @@ where
             .original_result()
     }
 
+    /// Structured proof: `proof_hash` becomes the job's current proof; the full record 
+    /// (with `uri`, `content_type`, submitter and timestamp) is appended to the job's 
+    /// proof history. 
+    pub fn submit_proof_with_details<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        proof_hash: Arg1,
+        uri: Arg2,
+        content_type: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("submit_proof_with_details")
+            .argument(&job_id)
+            .argument(&proof_hash)
+            .argument(&uri)
+            .argument(&content_type)
+            .original_result()
+    }
+
+    /// Batch variant of `submit_proof_with_details`. Every proof is checked as if 
+    /// submitted on its own; any failure reverts the whole batch. 
+    pub fn submit_proofs<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ProofInput<Env::Api>>>,
+    >(
+        self,
+        proofs: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("submit_proofs")
+            .argument(&proofs)
+            .original_result()
+    }
+
+    /// Relayed variant: anyone may submit a proof signed by the agent's registered 
+    /// Ed25519 public key. The signature covers `get_proof_signing_message` and is 
+    /// bound to the agent's current proof nonce, which is bumped on success. 
+    pub fn submit_proof_signed<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<TimestampMillis>,
+    >(
+        self,
+        job_id: Arg0,
+        proof: Arg1,
+        signature: Arg2,
+        expiry: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("submit_proof_signed")
+            .argument(&job_id)
+            .argument(&proof)
+            .argument(&signature)
+            .argument(&expiry)
+            .original_result()
+    }
+
     /// NFT-holder variant: proves ownership by sending the agent NFT. 
     /// The contract verifies token ID + nonce, executes proof, and returns the NFT. 
     pub fn submit_proof_with_nft<
@@ -136,8 +424,28 @@ where
             .original_result()
     }
 
+    /// Employer approves the validator whose response may verify the job. Required 
+    /// before validation when the job holds a payment or has an active escrow, so the 
+    /// agent owner cannot pick a validator that releases the funds. 
+    pub fn approve_validator<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        validator_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approve_validator")
+            .argument(&job_id)
+            .argument(&validator_address)
+            .original_result()
+    }
+
     /// ERC-8004: Agent requests validation from a specific validator. 
-    /// MUST be called by the owner of the agent (agentId). 
+    /// MUST be called by the owner of the agent (agentId), once a proof was submitted and 
+    /// before the job is verified. 
     pub fn validation_request<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
@@ -162,7 +470,8 @@ where
 
     /// ERC-8004: Validator responds with a result (0-100). 
     /// MUST be called by the validatorAddress from the original request. 
-    /// Can be called multiple times for progressive validation. 
+    /// Can be called multiple times for progressive validation; every call is 
+    /// appended to the request's response history. 
     pub fn validation_response<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<u8>,
@@ -173,7 +482,7 @@ where
         self,
         request_hash: Arg0,
         response: Arg1,
-        _response_uri: Arg2,
+        response_uri: Arg2,
         response_hash: Arg3,
         tag: Arg4,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -182,12 +491,14 @@ where
             .raw_call("validation_response")
             .argument(&request_hash)
             .argument(&response)
-            .argument(&_response_uri)
+            .argument(&response_uri)
             .argument(&response_hash)
             .argument(&tag)
             .original_result()
     }
 
+    /// Deletes the given jobs once they are older than the retention configured for their 
+    /// status. Jobs still referenced by an active escrow or awaiting feedback are skipped. 
     pub fn clean_old_jobs<
         Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
     >(
@@ -201,7 +512,7 @@ where
             .original_result()
     }
 
-    pub fn is_job_verified<
+    pub fn delivery_challenged<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
@@ -209,62 +520,835 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("is_job_verified")
+            .raw_call("is_delivery_challenged")
             .argument(&job_id)
             .original_result()
     }
 
-    pub fn get_job_data<
-        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    /// Last nonce used by `create_job` to generate an ID for this employer. 
+    pub fn employer_job_nonce<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        job_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<common::structs::JobData<Env::Api>>> {
+        employer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_job_data")
-            .argument(&job_id)
+            .raw_call("get_employer_job_nonce")
+            .argument(&employer)
             .original_result()
     }
 
-    /// ERC-8004: Returns validation status for a request hash. 
-    pub fn get_validation_status<
-        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    /// Replay protection for `submit_proof_signed`: the nonce the agent's next signature 
+    /// must cover. 
+    pub fn agent_proof_nonce<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        request_hash: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<common::structs::ValidationRequestData<Env::Api>>> {
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_validation_status")
-            .argument(&request_hash)
+            .raw_call("get_agent_proof_nonce")
+            .argument(&agent_nonce)
             .original_result()
     }
 
-    /// ERC-8004: Returns all validation request hashes for an agent. 
-    pub fn get_agent_validations<
-        Arg0: ProxyArg<u64>,
+    /// Optional: when set, jobs with an active escrow are never cleaned. 
+    pub fn escrow_contract_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_escrow_contract_address")
+            .original_result()
+    }
+
+    /// Optional: when set, verified jobs still awaiting feedback are never cleaned. 
+    pub fn reputation_contract_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_reputation_contract_address")
+            .original_result()
+    }
+
+    /// Votes one side needs to win a dispute. 
+    pub fn dispute_quorum(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_dispute_quorum")
+            .original_result()
+    }
+
+    pub fn is_job_verified<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
-        agent_nonce: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedBuffer<Env::Api>>> {
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_agent_validations")
-            .argument(&agent_nonce)
+            .raw_call("is_job_verified")
+            .argument(&job_id)
             .original_result()
     }
 
-    pub fn set_identity_registry_address<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Retention applied by `clean_old_jobs` to jobs in `status`. 
+    pub fn get_job_retention<
+        Arg0: ProxyArg<common::structs::JobStatus>,
     >(
         self,
-        address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        status: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationMillis> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("set_identity_registry_address")
-            .argument(&address)
+            .raw_call("get_job_retention")
+            .argument(&status)
             .original_result()
     }
+
+    /// Time after a job ends during which it is kept while awaiting a rating. 
+    pub fn get_feedback_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationMillis> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_feedback_window")
+            .original_result()
+    }
+
+    pub fn get_job_proof_count<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_proof_count")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Paginated proof history for a job, oldest first. 
+    /// `from` is a 0-based offset; at most `MAX_PAGE_SIZE` entries are returned. 
+    pub fn get_job_proofs<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        job_id: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, common::structs::ProofRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_proofs")
+            .argument(&job_id)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_job_timeline<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::JobTimeline> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_timeline")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_job_sla<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DurationMillis>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_sla")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_agent_delivery_metrics<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::AgentDeliveryMetrics> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_delivery_metrics")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// `(delivered_jobs, average_delivery_ms, on_time_ratio_bps)` — the ratio only 
+    /// counts jobs whose service had an SLA. 
+    pub fn get_agent_delivery_stats<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_delivery_stats")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    pub fn get_optimistic_window<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DurationMillis>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_optimistic_window")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_approved_validator<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_approved_validator")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_job_service_id<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_service_id")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Service price paid for the job at creation; `None` for unpaid jobs. 
+    pub fn get_job_payment<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Payment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_payment")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_held_payment<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Payment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_held_payment")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Time after the job's latest activity from which `claim_refund` is allowed. 
+    pub fn get_refund_timeout(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationMillis> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_refund_timeout")
+            .original_result()
+    }
+
+    /// Message the agent must sign for `submit_proof_signed`, bound to its current 
+    /// proof nonce. 
+    pub fn get_proof_signing_message<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<TimestampMillis>,
+    >(
+        self,
+        job_id: Arg0,
+        proof: Arg1,
+        expiry: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proof_signing_message")
+            .argument(&job_id)
+            .argument(&proof)
+            .argument(&expiry)
+            .original_result()
+    }
+
+    pub fn get_job_data<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<common::structs::JobData<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_data")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// `(requirements_hash, requirements_uri)` committed by the employer; both empty when 
+    /// the job was created without requirements. 
+    pub fn get_job_requirements<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_requirements")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_validation_status<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<common::structs::ValidationRequestData<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validation_status")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    /// ERC-8004: Returns all validation request hashes for an agent. 
+    /// Unbounded — prefer `get_agent_validations_page` for agents with many requests. 
+    pub fn get_agent_validations<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_validations")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Number of responses recorded for a request hash (progressive validation). 
+    pub fn get_validation_response_count<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validation_response_count")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    /// Latest response recorded for a request hash, including its `response_uri`, which 
+    /// the request itself does not store. 
+    pub fn get_latest_validation_response<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<common::structs::ValidationResponseRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_latest_validation_response")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    /// Paginated response history for a request hash, oldest first. 
+    /// `from` is a 0-based offset; at most `MAX_PAGE_SIZE` entries are returned. 
+    pub fn get_validation_responses<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        request_hash: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, common::structs::ValidationResponseRecord<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validation_responses")
+            .argument(&request_hash)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// ERC-8004 `getSummary`: number of responded requests for an agent and their average 
+    /// response, optionally filtered by validator set and tag. An empty `tag` or an empty 
+    /// validator list means no filter. Validators should be distinct. 
+    pub fn get_validation_summary<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        tag: Arg1,
+        validator_addresses: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u8>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validation_summary")
+            .argument(&agent_nonce)
+            .argument(&tag)
+            .argument(&validator_addresses)
+            .original_result()
+    }
+
+    pub fn get_agent_validation_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_validation_count")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Cursor-based page over an agent's validation request hashes, in request order. 
+    /// Scans at most `size` entries starting at `from` and returns the matching hashes 
+    /// together with the cursor to resume from; the scan is complete once the cursor 
+    /// equals `get_agent_validation_count`. 
+    /// Optional trailing filters: `validator`, `tag` (of the latest response) and `responded`. 
+    /// As they are positional, the zero address and the empty tag mean "any". 
+    pub fn get_agent_validations_page<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+        Arg4: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+        Arg5: ProxyArg<OptionalValue<bool>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        from: Arg1,
+        size: Arg2,
+        validator: Arg3,
+        tag: Arg4,
+        responded: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, ManagedVec<Env::Api, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_validations_page")
+            .argument(&agent_nonce)
+            .argument(&from)
+            .argument(&size)
+            .argument(&validator)
+            .argument(&tag)
+            .argument(&responded)
+            .original_result()
+    }
+
+    /// Number of entries in the agent's job index, including tombstones left by cleanup; 
+    /// this is the cursor bound for `get_agent_jobs_page`. 
+    pub fn get_agent_job_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_job_count")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Cursor-based page over an agent's job IDs, in creation order. 
+    /// Returns the job IDs (optionally only those in `status`) and the cursor to resume from. 
+    /// Cleaned-up jobs are skipped, so a page may hold fewer than `size` IDs. 
+    pub fn get_agent_jobs_page<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<OptionalValue<common::structs::JobStatus>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        from: Arg1,
+        size: Arg2,
+        status: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, ManagedVec<Env::Api, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_jobs_page")
+            .argument(&agent_nonce)
+            .argument(&from)
+            .argument(&size)
+            .argument(&status)
+            .original_result()
+    }
+
+    /// Same as `get_agent_job_count`, for the employer's job index. 
+    pub fn get_employer_job_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        employer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_employer_job_count")
+            .argument(&employer)
+            .original_result()
+    }
+
+    /// Cursor-based page over the jobs created by an employer, in creation order. 
+    pub fn get_employer_jobs_page<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<OptionalValue<common::structs::JobStatus>>,
+    >(
+        self,
+        employer: Arg0,
+        from: Arg1,
+        size: Arg2,
+        status: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, ManagedVec<Env::Api, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_employer_jobs_page")
+            .argument(&employer)
+            .argument(&from)
+            .argument(&size)
+            .argument(&status)
+            .original_result()
+    }
+
+    pub fn set_identity_registry_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_identity_registry_address")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_escrow_contract_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_escrow_contract_address")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_reputation_contract_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_reputation_contract_address")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// How long a job in `status` is kept before `clean_old_jobs` may delete it. 
+    pub fn set_job_retention<
+        Arg0: ProxyArg<common::structs::JobStatus>,
+        Arg1: ProxyArg<DurationMillis>,
+    >(
+        self,
+        status: Arg0,
+        retention: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_job_retention")
+            .argument(&status)
+            .argument(&retention)
+            .original_result()
+    }
+
+    /// How long after the job's latest activity an employer must wait before reclaiming 
+    /// a held payment. 
+    pub fn set_refund_timeout<
+        Arg0: ProxyArg<DurationMillis>,
+    >(
+        self,
+        timeout: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_refund_timeout")
+            .argument(&timeout)
+            .original_result()
+    }
+
+    /// How long after verification (or the last activity of a rejected or cancelled job) 
+    /// `clean_old_jobs` keeps a job that is still awaiting a rating. 
+    pub fn set_feedback_window<
+        Arg0: ProxyArg<DurationMillis>,
+    >(
+        self,
+        window: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_feedback_window")
+            .argument(&window)
+            .original_result()
+    }
+
+    pub fn open_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        reason_uri: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("open_dispute")
+            .argument(&job_id)
+            .argument(&reason_uri)
+            .original_result()
+    }
+
+    /// Arbiters of the panel snapshotted at opening vote until the deadline. The job's 
+    /// employer and agent owner cannot vote, even when they are arbiters. 
+    pub fn vote_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        job_id: Arg0,
+        for_agent: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("vote_dispute")
+            .argument(&job_id)
+            .argument(&for_agent)
+            .original_result()
+    }
+
+    /// Default outcome once the deadline passed without a decision. Callable by anyone. 
+    pub fn expire_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expire_dispute")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn add_arbiters<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        arbiters: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_arbiters")
+            .argument(&arbiters)
+            .original_result()
+    }
+
+    pub fn remove_arbiters<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        arbiters: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_arbiters")
+            .argument(&arbiters)
+            .original_result()
+    }
+
+    pub fn set_dispute_quorum<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        quorum: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_dispute_quorum")
+            .argument(&quorum)
+            .original_result()
+    }
+
+    /// How long arbiters have to decide disputes opened from now on. 
+    pub fn set_dispute_period<
+        Arg0: ProxyArg<DurationMillis>,
+    >(
+        self,
+        period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_dispute_period")
+            .argument(&period)
+            .original_result()
+    }
+
+    /// Bond required to open a dispute; a zero amount removes the requirement. 
+    pub fn set_dispute_bond<
+        Arg0: ProxyArg<TokenId<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_dispute_bond")
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn get_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DisputeData<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_dispute")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_dispute_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationMillis> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_dispute_period")
+            .original_result()
+    }
+
+    pub fn get_arbiters(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_arbiters")
+            .original_result()
+    }
+
+    pub fn get_dispute_bond(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Payment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_dispute_bond")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobInput<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub job_id: ManagedBuffer<Api>,
+    pub agent_nonce: u64,
+    pub service_id: Option<u32>,
+    pub requirements_hash: ManagedBuffer<Api>,
+    pub requirements_uri: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ProofInput<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub job_id: ManagedBuffer<Api>,
+    pub proof_hash: ManagedBuffer<Api>,
+    pub uri: ManagedBuffer<Api>,
+    pub content_type: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum DisputeStatus {
+    Open,
+    ResolvedForAgent,
+    ResolvedForEmployer,
+    Expired,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct DisputeData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub opener: ManagedAddress<Api>,
+    pub reason_uri: ManagedBuffer<Api>,
+    pub bond: Option<Payment<Api>>,
+    pub opened_at: TimestampMillis,
+    pub quorum: u32,
+    pub deadline: TimestampMillis,
+    pub votes_for_agent: u32,
+    pub votes_for_employer: u32,
+    pub status: DisputeStatus,
 }
@@ -1,5 +1,8 @@
 multiversx_sc::imports!();
 
+use crate::errors::ERR_INVALID_FEEDBACK_WINDOW;
+use crate::structs::JobStatus;
+
 #[multiversx_sc::module]
 pub trait ConfigModule:
     common::cross_contract::CrossContractModule + crate::storage::ExternalStorageModule
@@ -9,4 +12,40 @@ pub trait ConfigModule:
     fn set_identity_registry_address(&self, address: ManagedAddress) {
         self.identity_registry_address().set(&address);
     }
+
+    #[only_owner]
+    #[endpoint(set_escrow_contract_address)]
+    fn set_escrow_contract_address(&self, address: ManagedAddress) {
+        self.escrow_contract_address().set(&address);
+    }
+
+    #[only_owner]
+    #[endpoint(set_reputation_contract_address)]
+    fn set_reputation_contract_address(&self, address: ManagedAddress) {
+        self.reputation_contract_address().set(&address);
+    }
+
+    /// How long a job in `status` is kept before `clean_old_jobs` may delete it.
+    #[only_owner]
+    #[endpoint(set_job_retention)]
+    fn set_job_retention(&self, status: JobStatus, retention: DurationMillis) {
+        self.job_retention(&status).set(retention);
+    }
+
+    /// How long after the job's latest activity an employer must wait before reclaiming
+    /// a held payment.
+    #[only_owner]
+    #[endpoint(set_refund_timeout)]
+    fn set_refund_timeout(&self, timeout: DurationMillis) {
+        self.refund_timeout().set(timeout);
+    }
+
+    /// How long after verification (or the last activity of a rejected or cancelled job)
+    /// `clean_old_jobs` keeps a job that is still awaiting a rating.
+    #[only_owner]
+    #[endpoint(set_feedback_window)]
+    fn set_feedback_window(&self, window: DurationMillis) {
+        require!(window > DurationMillis::zero(), ERR_INVALID_FEEDBACK_WINDOW);
+        self.feedback_window().set(window);
+    }
 }
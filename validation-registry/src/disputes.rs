@@ -0,0 +1,285 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::structs::{DisputeData, DisputeStatus, JobStatus};
+
+pub const DEFAULT_DISPUTE_PERIOD: DurationMillis = DurationMillis::new(14 * 24 * 60 * 60 * 1000);
+
+/// Disputes on delivered or verified jobs whose payment is still held, by this contract or
+/// by an active escrow. Either party opens a dispute (posting the configured bond), the
+/// arbiters registered at that time (other than the parties) vote, and the first side to
+/// reach the quorum wins:
+/// - agent wins: the job becomes `Verified` (held payment released to the agent owner);
+/// - employer wins: the job becomes `Rejected` (held payment refunded, escrow refundable).
+///
+/// The bond goes to the winning side. Without a decision by the deadline, anyone can
+/// `expire_dispute`: the job is cancelled, the held payment refunded and the bond
+/// returned to the opener.
+#[multiversx_sc::module]
+pub trait DisputesModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+{
+    #[payable("*")]
+    #[endpoint(open_dispute)]
+    fn open_dispute(&self, job_id: ManagedBuffer, reason_uri: ManagedBuffer) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let mut job_data = job_mapper.get();
+        let caller = self.blockchain().get_caller();
+        let agent_owner = self.agent_owner_of(&job_data);
+        require!(
+            caller == job_data.employer || caller == agent_owner,
+            ERR_NOT_JOB_PARTY
+        );
+        require!(
+            job_data.status == JobStatus::Pending
+                || job_data.status == JobStatus::ValidationRequested
+                || job_data.status == JobStatus::Verified,
+            ERR_JOB_NOT_DISPUTABLE
+        );
+
+        let dispute_mapper = self.disputes(&job_id);
+        require!(dispute_mapper.is_empty(), ERR_DISPUTE_ALREADY_OPENED);
+        require!(
+            !self.held_payment(&job_id).is_empty() || self.has_active_escrow(&job_id, &job_data),
+            ERR_NOTHING_TO_DISPUTE
+        );
+
+        // Arbiters who are a party to the job cannot vote, so they are left off the panel
+        // and the quorum must be reachable without them
+        let mut panel = self.dispute_arbiters(&job_id);
+        for arbiter in self.arbiters().iter() {
+            if arbiter != job_data.employer && arbiter != agent_owner {
+                panel.insert(arbiter);
+            }
+        }
+        let quorum = self.dispute_quorum().get();
+        require!(
+            quorum > 0 && panel.len() >= quorum as usize,
+            ERR_NO_ARBITER_PANEL
+        );
+
+        let bond = self.call_value().single_optional().map(|pay| pay.clone());
+        let bond_mapper = self.dispute_bond();
+        if bond_mapper.is_empty() {
+            require!(bond.is_none(), ERR_INVALID_BOND);
+        } else {
+            require!(
+                bond.as_ref().is_some_and(|pay| pay == &bond_mapper.get()),
+                ERR_INVALID_BOND
+            );
+        }
+
+        let opened_at = self.blockchain().get_block_timestamp_millis();
+        dispute_mapper.set(DisputeData {
+            opener: caller.clone(),
+            reason_uri: reason_uri.clone(),
+            bond,
+            opened_at,
+            quorum,
+            deadline: opened_at + self.get_dispute_period_or_default(),
+            votes_for_agent: 0,
+            votes_for_employer: 0,
+            status: DisputeStatus::Open,
+        });
+
+        job_data.status = JobStatus::Disputed;
+        job_mapper.set(&job_data);
+
+        self.dispute_opened_event(&job_id, &caller, &reason_uri);
+    }
+
+    /// Arbiters of the panel snapshotted at opening vote until the deadline. The job's
+    /// employer and agent owner cannot vote, even when they are arbiters.
+    #[endpoint(vote_dispute)]
+    fn vote_dispute(&self, job_id: ManagedBuffer, for_agent: bool) {
+        let caller = self.blockchain().get_caller();
+        let dispute_mapper = self.disputes(&job_id);
+        require!(!dispute_mapper.is_empty(), ERR_DISPUTE_NOT_FOUND);
+
+        let job_data = self.job_data(&job_id).get();
+        require!(
+            caller != job_data.employer && caller != self.agent_owner_of(&job_data),
+            ERR_PARTY_CANNOT_VOTE
+        );
+        require!(
+            self.dispute_arbiters(&job_id).contains(&caller),
+            ERR_NOT_ARBITER
+        );
+
+        let mut dispute = dispute_mapper.get();
+        require!(dispute.status == DisputeStatus::Open, ERR_DISPUTE_NOT_OPEN);
+        require!(
+            self.blockchain().get_block_timestamp_millis() <= dispute.deadline,
+            ERR_DISPUTE_VOTING_CLOSED
+        );
+
+        require!(
+            self.dispute_voters(&job_id).insert(caller.clone()),
+            ERR_ALREADY_VOTED
+        );
+
+        if for_agent {
+            dispute.votes_for_agent += 1;
+        } else {
+            dispute.votes_for_employer += 1;
+        }
+        self.dispute_vote_event(&job_id, &caller, for_agent);
+
+        if dispute.votes_for_agent >= dispute.quorum {
+            dispute.status = DisputeStatus::ResolvedForAgent;
+        } else if dispute.votes_for_employer >= dispute.quorum {
+            dispute.status = DisputeStatus::ResolvedForEmployer;
+        }
+
+        if dispute.status == DisputeStatus::Open {
+            dispute_mapper.set(&dispute);
+            return;
+        }
+
+        dispute_mapper.set(&dispute);
+        self.resolve_dispute(&job_id, &dispute.status, dispute.bond);
+    }
+
+    fn resolve_dispute(
+        &self,
+        job_id: &ManagedBuffer,
+        status: &DisputeStatus,
+        bond: Option<Payment<Self::Api>>,
+    ) {
+        let mut job_data = self.job_data(job_id).get();
+        let winner = if status == &DisputeStatus::ResolvedForAgent {
+            let agent_owner = self.agent_owner_of(&job_data);
+            self.complete_verification(job_id, job_data);
+            agent_owner
+        } else {
+            let employer = job_data.employer.clone();
+            job_data.status = JobStatus::Rejected;
+            self.job_data(job_id).set(&job_data);
+
+            self.refund_held_payment(job_id, &employer);
+            employer
+        };
+
+        if let Some(bond) = bond {
+            self.tx().to(&winner).payment(bond).transfer();
+        }
+
+        self.dispute_resolved_event(job_id, status);
+    }
+
+    /// Default outcome once the deadline passed without a decision. Callable by anyone.
+    #[endpoint(expire_dispute)]
+    fn expire_dispute(&self, job_id: ManagedBuffer) {
+        let dispute_mapper = self.disputes(&job_id);
+        require!(!dispute_mapper.is_empty(), ERR_DISPUTE_NOT_FOUND);
+
+        let mut dispute = dispute_mapper.get();
+        require!(dispute.status == DisputeStatus::Open, ERR_DISPUTE_NOT_OPEN);
+        require!(
+            self.blockchain().get_block_timestamp_millis() > dispute.deadline,
+            ERR_DISPUTE_VOTING_OPEN
+        );
+
+        dispute.status = DisputeStatus::Expired;
+        dispute_mapper.set(&dispute);
+
+        let employer = self.job_data(&job_id).get().employer;
+        self.cancel_and_refund(&job_id, &employer);
+        if let Some(bond) = dispute.bond {
+            self.tx().to(&dispute.opener).payment(bond).transfer();
+        }
+
+        self.dispute_resolved_event(&job_id, &dispute.status);
+    }
+
+    fn get_dispute_period_or_default(&self) -> DurationMillis {
+        let mapper = self.dispute_period();
+        if mapper.is_empty() {
+            DEFAULT_DISPUTE_PERIOD
+        } else {
+            mapper.get()
+        }
+    }
+
+    // ── Arbiter panel (owner) ──
+
+    #[only_owner]
+    #[endpoint(add_arbiters)]
+    fn add_arbiters(&self, arbiters: MultiValueEncoded<ManagedAddress>) {
+        let mut mapper = self.arbiters();
+        for arbiter in arbiters {
+            mapper.insert(arbiter);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(remove_arbiters)]
+    fn remove_arbiters(&self, arbiters: MultiValueEncoded<ManagedAddress>) {
+        let mut mapper = self.arbiters();
+        for arbiter in arbiters {
+            mapper.swap_remove(&arbiter);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(set_dispute_quorum)]
+    fn set_dispute_quorum(&self, quorum: u32) {
+        require!(quorum > 0, ERR_INVALID_QUORUM);
+        self.dispute_quorum().set(quorum);
+    }
+
+    /// How long arbiters have to decide disputes opened from now on.
+    #[only_owner]
+    #[endpoint(set_dispute_period)]
+    fn set_dispute_period(&self, period: DurationMillis) {
+        require!(period > DurationMillis::zero(), ERR_INVALID_DISPUTE_PERIOD);
+        self.dispute_period().set(period);
+    }
+
+    /// Bond required to open a dispute; a zero amount removes the requirement.
+    #[only_owner]
+    #[endpoint(set_dispute_bond)]
+    fn set_dispute_bond(&self, token_id: TokenId, amount: BigUint) {
+        match NonZeroBigUint::new(amount) {
+            Some(amount) => self.dispute_bond().set(Payment::new(token_id, 0, amount)),
+            None => self.dispute_bond().clear(),
+        }
+    }
+
+    // ── Views ──
+
+    #[view(get_dispute)]
+    fn get_dispute(&self, job_id: ManagedBuffer) -> OptionalValue<DisputeData<Self::Api>> {
+        let mapper = self.disputes(&job_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(get_dispute_period)]
+    fn get_dispute_period(&self) -> DurationMillis {
+        self.get_dispute_period_or_default()
+    }
+
+    #[view(get_arbiters)]
+    fn get_arbiters(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.arbiters().iter().collect()
+    }
+
+    #[view(get_dispute_bond)]
+    fn get_dispute_bond(&self) -> OptionalValue<Payment<Self::Api>> {
+        let mapper = self.dispute_bond();
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub use common::errors::ERR_AGENT_NOT_FOUND;
 pub use common::errors::ERR_JOB_NOT_FOUND;
 pub use common::errors::ERR_NOT_AGENT_OWNER;
 
@@ -6,6 +7,47 @@ pub const ERR_INSUFFICIENT_PAYMENT: &str = "Insufficient payment";
 pub const ERR_SERVICE_NOT_FOUND: &str = "Service config not found for agent";
 pub const ERR_INVALID_PAYMENT: &str = "Invalid payment token";
 pub const ERR_VALIDATION_REQUEST_NOT_FOUND: &str = "Validation request not found";
+pub const ERR_VALIDATION_REQUEST_EXISTS: &str = "Validation request hash already used";
 pub const ERR_NOT_VALIDATOR: &str = "Only the designated validator can respond";
 pub const ERR_INVALID_AGENT_NFT: &str = "Invalid agent NFT: wrong token ID or nonce";
 pub const ERR_JOB_STATUS_INVALID: &str = "Job status invalid for proof submission";
+pub const ERR_PAYMENT_WITHOUT_SERVICE: &str = "Payment not accepted without a service ID";
+pub const ERR_NOT_EMPLOYER: &str = "Only the employer can call this";
+pub const ERR_JOB_CANCELLED: &str = "Job has been cancelled";
+pub const ERR_JOB_NOT_CANCELLABLE: &str = "Job can only be cancelled before proof submission";
+pub const ERR_NO_HELD_PAYMENT: &str = "No held payment for this job";
+pub const ERR_REFUND_TIMEOUT_NOT_PASSED: &str = "Refund timeout has not passed yet";
+pub const ERR_JOB_ALREADY_VERIFIED: &str = "Job already verified";
+pub const ERR_NO_DELIVERY: &str = "Job has no delivered proof";
+pub const ERR_OPTIMISTIC_NOT_ENABLED: &str = "Optimistic verification not enabled for this job";
+pub const ERR_DELIVERY_CHALLENGED: &str = "Delivery has been challenged";
+pub const ERR_CHALLENGE_WINDOW_OPEN: &str = "Challenge window has not ended yet";
+pub const ERR_CHALLENGE_WINDOW_CLOSED: &str = "Challenge window has ended";
+pub const ERR_JOB_DISPUTED: &str = "Job is under dispute";
+pub const ERR_JOB_REJECTED: &str = "Job was rejected in a dispute";
+pub const ERR_NOT_JOB_PARTY: &str = "Only the employer or the agent owner can call this";
+pub const ERR_JOB_NOT_DISPUTABLE: &str = "Job cannot be disputed in its current status";
+pub const ERR_DISPUTE_ALREADY_OPENED: &str = "A dispute was already opened for this job";
+pub const ERR_DISPUTE_NOT_FOUND: &str = "Dispute not found";
+pub const ERR_DISPUTE_NOT_OPEN: &str = "Dispute is not open";
+pub const ERR_INVALID_BOND: &str = "Invalid dispute bond";
+pub const ERR_NO_ARBITER_PANEL: &str = "Not enough arbiters for the dispute quorum";
+pub const ERR_NOT_ARBITER: &str = "Only a registered arbiter can vote";
+pub const ERR_ALREADY_VOTED: &str = "Arbiter already voted on this dispute";
+pub const ERR_PAYMENT_COUNT_MISMATCH: &str =
+    "Number of payments must match the number of jobs with a paid service";
+pub const ERR_INVALID_QUORUM: &str = "Quorum must be greater than zero";
+pub const ERR_SIGNATURE_EXPIRED: &str = "Signature has expired";
+pub const ERR_INVALID_PUBLIC_KEY: &str = "Agent public key is not a valid Ed25519 key";
+pub const ERR_INVALID_FEEDBACK_WINDOW: &str = "Feedback window must be greater than zero";
+pub const ERR_TOO_MANY_PROOFS: &str = "Proof limit reached for this job";
+pub const ERR_OPTIMISTIC_AFTER_DELIVERY: &str =
+    "Optimistic verification can only be enabled before delivery";
+pub const ERR_INVALID_CHALLENGE_WINDOW: &str = "Challenge window must be between 1 ms and 30 days";
+pub const ERR_EMPLOYER_IS_AGENT_OWNER: &str = "Agent owner cannot verify a job for their own agent";
+pub const ERR_NOTHING_TO_DISPUTE: &str = "Job has no held payment or active escrow to dispute";
+pub const ERR_PARTY_CANNOT_VOTE: &str = "Job parties cannot vote on their own dispute";
+pub const ERR_DISPUTE_VOTING_CLOSED: &str = "Dispute voting period has ended";
+pub const ERR_DISPUTE_VOTING_OPEN: &str = "Dispute voting period has not ended yet";
+pub const ERR_INVALID_DISPUTE_PERIOD: &str = "Dispute period must be greater than zero";
+pub const ERR_VALIDATOR_NOT_APPROVED: &str = "Validator was not approved by the employer";
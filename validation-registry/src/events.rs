@@ -1,7 +1,7 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::ValidationRequestData;
+use crate::structs::{DisputeStatus, ProofRecord, ValidationRequestData};
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -22,4 +22,92 @@ pub trait EventsModule {
         #[indexed] request_hash: ManagedBuffer,
         data: ValidationRequestData<Self::Api>,
     );
+
+    #[event("jobCreated")]
+    fn job_created_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        #[indexed] agent_nonce: u64,
+        #[indexed] requirements_hash: &ManagedBuffer,
+        requirements_uri: &ManagedBuffer,
+    );
+
+    #[event("jobsCleaned")]
+    fn jobs_cleaned_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        job_ids: ManagedVec<ManagedBuffer>,
+    );
+
+    #[event("jobCancelled")]
+    fn job_cancelled_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+    );
+
+    #[event("heldPaymentReleased")]
+    fn held_payment_released_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] receiver: &ManagedAddress,
+        payment: &Payment<Self::Api>,
+    );
+
+    #[event("heldPaymentRefunded")]
+    fn held_payment_refunded_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        payment: &Payment<Self::Api>,
+    );
+
+    #[event("proofSubmitted")]
+    fn proof_submitted_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] submitter: &ManagedAddress,
+        proof: &ProofRecord<Self::Api>,
+    );
+
+    #[event("deliveryAccepted")]
+    fn delivery_accepted_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+    );
+
+    #[event("deliveryChallenged")]
+    fn delivery_challenged_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+    );
+
+    #[event("jobFinalized")]
+    fn job_finalized_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("disputeOpened")]
+    fn dispute_opened_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] opener: &ManagedAddress,
+        reason_uri: &ManagedBuffer,
+    );
+
+    #[event("disputeVote")]
+    fn dispute_vote_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] arbiter: &ManagedAddress,
+        #[indexed] for_agent: bool,
+    );
+
+    #[event("disputeResolved")]
+    fn dispute_resolved_event(&self, #[indexed] job_id: &ManagedBuffer, status: &DisputeStatus);
 }
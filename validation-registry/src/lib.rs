@@ -4,17 +4,18 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 pub mod config;
+pub mod disputes;
 pub mod errors;
 pub mod events;
 pub mod storage;
 pub mod structs;
+pub mod utils;
 pub mod views;
 
 pub use structs::*;
 
 use errors::*;
-
-const THREE_DAYS: DurationMillis = DurationMillis::new(3 * 24 * 60 * 60 * 1000);
+use utils::{MAX_CHALLENGE_WINDOW, MIN_PASSING_RESPONSE};
 
 #[multiversx_sc::contract]
 pub trait ValidationRegistry:
@@ -23,6 +24,8 @@ pub trait ValidationRegistry:
     + views::ViewsModule
     + events::EventsModule
     + config::ConfigModule
+    + utils::UtilsModule
+    + disputes::DisputesModule
 {
     #[init]
     fn init(&self, identity_registry_address: ManagedAddress) {
@@ -36,92 +39,356 @@ pub trait ValidationRegistry:
     #[payable("*")]
     #[endpoint(init_job)]
     fn init_job(&self, job_id: ManagedBuffer, agent_nonce: u64, service_id: OptionalValue<u32>) {
-        let job_mapper = self.job_data(&job_id);
-        require!(job_mapper.is_empty(), ERR_JOB_ALREADY_INITIALIZED);
+        self.init_job_with_requirements(
+            job_id,
+            agent_nonce,
+            ManagedBuffer::new(),
+            ManagedBuffer::new(),
+            service_id,
+        );
+    }
 
+    /// Like `init_job`, but the employer commits to the job's requirements: the hash (and
+    /// where to fetch the document) are stored on the job for validators to check proofs
+    /// against.
+    #[payable("*")]
+    #[endpoint(init_job_with_requirements)]
+    fn init_job_with_requirements(
+        &self,
+        job_id: ManagedBuffer,
+        agent_nonce: u64,
+        requirements_hash: ManagedBuffer,
+        requirements_uri: ManagedBuffer,
+        service_id: OptionalValue<u32>,
+    ) {
         let caller = self.blockchain().get_caller();
-        job_mapper.set(JobData {
-            status: JobStatus::New,
-            proof: ManagedBuffer::new(),
-            employer: caller,
-            creation_timestamp: self.blockchain().get_block_timestamp_millis(),
-            agent_nonce,
-        });
+        let payment = self.call_value().single_optional().map(|pay| pay.clone());
+        self.register_job(
+            JobInput {
+                job_id,
+                agent_nonce,
+                service_id: service_id.into_option(),
+                requirements_hash,
+                requirements_uri,
+            },
+            &caller,
+            payment,
+            false,
+        );
+    }
 
-        // If service_id provided, validate payment and forward to agent owner
-        if let OptionalValue::Some(sid) = service_id {
-            let identity_addr = self.identity_registry_address().get();
-            let agent_owner = self
-                .external_agents(identity_addr.clone())
-                .get_value(&agent_nonce);
-
-            let service_config_map = self.external_agent_service_config(identity_addr, agent_nonce);
-
-            if let Some(service_payment) = service_config_map.get(&sid) {
-                if let Some(pay) = self.call_value().single_optional() {
-                    require!(
-                        pay.token_identifier == service_payment.token_identifier
-                            && pay.token_nonce == service_payment.token_nonce,
-                        ERR_INVALID_PAYMENT
-                    );
-
-                    require!(
-                        pay.amount >= service_payment.amount,
-                        ERR_INSUFFICIENT_PAYMENT
-                    );
-
-                    if pay.amount > 0u64 {
-                        self.tx().to(&agent_owner).payment(pay.clone()).transfer();
-                    }
-                } else {
-                    // No payment sent — only valid if service is free
-                    require!(service_payment.amount == 0u64, ERR_INSUFFICIENT_PAYMENT);
-                }
-            }
+    /// Batch variant of `init_job`. Payments are matched in order to the jobs that name a
+    /// service: the first payment pays the first job with a `service_id`, and so on. Jobs
+    /// without a service take no payment. The number of payments must match exactly.
+    #[payable("*")]
+    #[endpoint(init_jobs)]
+    fn init_jobs(&self, jobs: MultiValueEncoded<JobInput<Self::Api>>) {
+        let caller = self.blockchain().get_caller();
+        let payments = self.call_value().all();
+        let mut payments_iter = payments.iter();
+        for job in jobs {
+            let payment = if self.is_paid_service(job.agent_nonce, job.service_id) {
+                let payment = payments_iter
+                    .next()
+                    .unwrap_or_else(|| sc_panic!(ERR_PAYMENT_COUNT_MISMATCH));
+                Some(payment.clone())
+            } else {
+                None
+            };
+            self.register_job(job, &caller, payment, false);
         }
+        require!(payments_iter.next().is_none(), ERR_PAYMENT_COUNT_MISMATCH);
     }
 
-    #[endpoint(submit_proof)]
-    fn submit_proof(&self, job_id: ManagedBuffer, proof: ManagedBuffer) {
+    /// Same as `init_job`, but the job ID is generated on-chain from the caller's address,
+    /// a per-employer nonce and the block nonce. Generated IDs cannot be front-run: if an
+    /// ID is already taken, the next nonce is used. Returns the new job ID.
+    #[payable("*")]
+    #[endpoint(create_job)]
+    fn create_job(&self, agent_nonce: u64, service_id: OptionalValue<u32>) -> ManagedBuffer {
+        self.create_job_with_requirements(
+            agent_nonce,
+            ManagedBuffer::new(),
+            ManagedBuffer::new(),
+            service_id,
+        )
+    }
+
+    /// `create_job` with a requirements commitment, as in `init_job_with_requirements`.
+    #[payable("*")]
+    #[endpoint(create_job_with_requirements)]
+    fn create_job_with_requirements(
+        &self,
+        agent_nonce: u64,
+        requirements_hash: ManagedBuffer,
+        requirements_uri: ManagedBuffer,
+        service_id: OptionalValue<u32>,
+    ) -> ManagedBuffer {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_optional().map(|pay| pay.clone());
+        let job_id = self.generate_job_id(&caller);
+        self.register_job(
+            JobInput {
+                job_id: job_id.clone(),
+                agent_nonce,
+                service_id: service_id.into_option(),
+                requirements_hash,
+                requirements_uri,
+            },
+            &caller,
+            payment,
+            false,
+        );
+        job_id
+    }
+
+    /// Protocol-held payment mode: like `init_job` with a service, but the payment stays
+    /// in the contract until the job is verified. Until then the employer can `cancel_job`
+    /// (before any proof) or, once the refund timeout has passed, `claim_refund`.
+    #[payable("*")]
+    #[endpoint(init_job_held)]
+    fn init_job_held(&self, job_id: ManagedBuffer, agent_nonce: u64, service_id: u32) {
+        self.init_job_held_with_requirements(
+            job_id,
+            agent_nonce,
+            ManagedBuffer::new(),
+            ManagedBuffer::new(),
+            service_id,
+        );
+    }
+
+    /// `init_job_held` with a requirements commitment, as in `init_job_with_requirements`.
+    #[payable("*")]
+    #[endpoint(init_job_held_with_requirements)]
+    fn init_job_held_with_requirements(
+        &self,
+        job_id: ManagedBuffer,
+        agent_nonce: u64,
+        requirements_hash: ManagedBuffer,
+        requirements_uri: ManagedBuffer,
+        service_id: u32,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_optional().map(|pay| pay.clone());
+        self.register_job(
+            JobInput {
+                job_id,
+                agent_nonce,
+                service_id: Some(service_id),
+                requirements_hash,
+                requirements_uri,
+            },
+            &caller,
+            payment,
+            true,
+        );
+    }
+
+    /// Employer cancels a held-payment job before the agent submitted a proof and gets
+    /// the payment back. Jobs paid directly cannot be cancelled: the agent owner already
+    /// has the payment.
+    #[endpoint(cancel_job)]
+    fn cancel_job(&self, job_id: ManagedBuffer) {
         let job_mapper = self.job_data(&job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        require!(!self.held_payment(&job_id).is_empty(), ERR_NO_HELD_PAYMENT);
 
         let job_data = job_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(caller == job_data.employer, ERR_NOT_EMPLOYER);
+        require!(job_data.status == JobStatus::New, ERR_JOB_NOT_CANCELLABLE);
+
+        self.cancel_and_refund(&job_id, &caller);
+    }
 
-        // Security Check 1: Agent Owner or Registered Agent
+    /// Employer reclaims a held payment for a job that was not verified within the
+    /// refund timeout, counted from the job's latest activity. The job is cancelled.
+    #[endpoint(claim_refund)]
+    fn claim_refund(&self, job_id: ManagedBuffer) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        require!(!self.held_payment(&job_id).is_empty(), ERR_NO_HELD_PAYMENT);
+
+        let job_data = job_mapper.get();
         let caller = self.blockchain().get_caller();
-        let identity_addr = self.identity_registry_address().get();
+        require!(caller == job_data.employer, ERR_NOT_EMPLOYER);
+        require!(
+            job_data.status != JobStatus::Verified,
+            ERR_JOB_ALREADY_VERIFIED
+        );
+        require!(job_data.status != JobStatus::Disputed, ERR_JOB_DISPUTED);
 
-        // Optimization: Check Agent first (most frequent caller)
-        let agent_details = self
-            .external_agent_details(identity_addr.clone(), job_data.agent_nonce)
-            .get();
-
-        let mut is_authorized = false;
-        if caller.as_managed_buffer() == &agent_details.public_key {
-            is_authorized = true;
-        } else {
-            // Fallback: Check Agent Owner
-            let agent_owner = self
-                .external_agents(identity_addr)
-                .get_value(&job_data.agent_nonce);
-
-            if caller == agent_owner {
-                is_authorized = true;
-            }
+        let current_time = self.blockchain().get_block_timestamp_millis();
+        require!(
+            current_time
+                > self.last_job_activity(&job_id, &job_data) + self.get_refund_timeout_or_default(),
+            ERR_REFUND_TIMEOUT_NOT_PASSED
+        );
+
+        self.cancel_and_refund(&job_id, &caller);
+    }
+
+    /// Employer verifies the delivered job directly, without a validator.
+    #[endpoint(accept_delivery)]
+    fn accept_delivery(&self, job_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        let job_data = self.require_employer_with_delivery(&job_id, &caller);
+        self.require_employer_not_agent_owner(&job_data);
+
+        self.mark_job_verified(&job_id);
+        self.delivery_accepted_event(&job_id, &caller);
+    }
+
+    /// Employer opts the job into optimistic verification: once a proof is submitted,
+    /// anyone can `finalize_job` after `challenge_window` unless the employer challenges.
+    /// Only possible before the agent delivers, with a window of at most
+    /// `MAX_CHALLENGE_WINDOW`.
+    #[endpoint(enable_optimistic_verification)]
+    fn enable_optimistic_verification(
+        &self,
+        job_id: ManagedBuffer,
+        challenge_window: DurationMillis,
+    ) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let job_data = job_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(caller == job_data.employer, ERR_NOT_EMPLOYER);
+        require!(
+            job_data.status == JobStatus::New,
+            ERR_OPTIMISTIC_AFTER_DELIVERY
+        );
+        require!(
+            challenge_window > DurationMillis::zero() && challenge_window <= MAX_CHALLENGE_WINDOW,
+            ERR_INVALID_CHALLENGE_WINDOW
+        );
+        self.require_employer_not_agent_owner(&job_data);
+
+        self.optimistic_window(&job_id).set(challenge_window);
+    }
+
+    /// Employer disputes an optimistic delivery within the challenge window. The job then
+    /// needs `accept_delivery` or a validator response to be verified.
+    #[endpoint(challenge_delivery)]
+    fn challenge_delivery(&self, job_id: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_employer_with_delivery(&job_id, &caller);
+
+        let current_time = self.blockchain().get_block_timestamp_millis();
+        require!(
+            current_time <= self.optimistic_deadline(&job_id),
+            ERR_CHALLENGE_WINDOW_CLOSED
+        );
+
+        self.delivery_challenged(&job_id).set(true);
+        self.delivery_challenged_event(&job_id, &caller);
+    }
+
+    /// Verifies an optimistic job whose challenge window has passed without a challenge.
+    /// Callable by anyone.
+    #[endpoint(finalize_job)]
+    fn finalize_job(&self, job_id: ManagedBuffer) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        self.require_delivered(&job_mapper.get());
+        require!(
+            !self.delivery_challenged(&job_id).get(),
+            ERR_DELIVERY_CHALLENGED
+        );
+
+        let current_time = self.blockchain().get_block_timestamp_millis();
+        require!(
+            current_time > self.optimistic_deadline(&job_id),
+            ERR_CHALLENGE_WINDOW_OPEN
+        );
+
+        self.mark_job_verified(&job_id);
+        self.job_finalized_event(&job_id, &self.blockchain().get_caller());
+    }
+
+    #[endpoint(submit_proof)]
+    fn submit_proof(&self, job_id: ManagedBuffer, proof: ManagedBuffer) {
+        self.submit_proof_with_details(job_id, proof, ManagedBuffer::new(), ManagedBuffer::new());
+    }
+
+    /// Structured proof: `proof_hash` becomes the job's current proof; the full record
+    /// (with `uri`, `content_type`, submitter and timestamp) is appended to the job's
+    /// proof history.
+    #[endpoint(submit_proof_with_details)]
+    fn submit_proof_with_details(
+        &self,
+        job_id: ManagedBuffer,
+        proof_hash: ManagedBuffer,
+        uri: ManagedBuffer,
+        content_type: ManagedBuffer,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.submit_proof_as(&caller, &job_id, proof_hash, uri, content_type);
+    }
+
+    /// Batch variant of `submit_proof_with_details`. Every proof is checked as if
+    /// submitted on its own; any failure reverts the whole batch.
+    #[endpoint(submit_proofs)]
+    fn submit_proofs(&self, proofs: MultiValueEncoded<ProofInput<Self::Api>>) {
+        let caller = self.blockchain().get_caller();
+        for proof in proofs {
+            self.submit_proof_as(
+                &caller,
+                &proof.job_id,
+                proof.proof_hash,
+                proof.uri,
+                proof.content_type,
+            );
         }
-        require!(is_authorized, ERR_NOT_AGENT_OWNER);
+    }
+
+    /// Relayed variant: anyone may submit a proof signed by the agent's registered
+    /// Ed25519 public key. The signature covers `get_proof_signing_message` and is
+    /// bound to the agent's current proof nonce, which is bumped on success.
+    #[endpoint(submit_proof_signed)]
+    fn submit_proof_signed(
+        &self,
+        job_id: ManagedBuffer,
+        proof: ManagedBuffer,
+        signature: ManagedBuffer,
+        expiry: TimestampMillis,
+    ) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
 
-        // Security Check 2: Ensure job is in correct state
+        let job_data = job_mapper.get();
         require!(
             job_data.status == JobStatus::New || job_data.status == JobStatus::Pending,
             ERR_JOB_STATUS_INVALID
         );
+        require!(
+            self.blockchain().get_block_timestamp_millis() <= expiry,
+            ERR_SIGNATURE_EXPIRED
+        );
 
-        job_mapper.update(|job| {
-            job.proof = proof;
-            job.status = JobStatus::Pending;
-        });
+        let identity_addr = self.identity_registry_address().get();
+        let public_key = self
+            .external_agent_details(identity_addr, job_data.agent_nonce)
+            .get()
+            .public_key;
+        let agent_address = ManagedAddress::try_from(public_key)
+            .unwrap_or_else(|_| sc_panic!(ERR_INVALID_PUBLIC_KEY));
+
+        let nonce_mapper = self.agent_proof_nonce(job_data.agent_nonce);
+        let nonce = nonce_mapper.get();
+        let message = self.compute_proof_signing_message(&job_id, &proof, nonce, expiry);
+        self.crypto()
+            .verify_ed25519(agent_address.as_managed_buffer(), &message, &signature);
+        nonce_mapper.set(nonce + 1);
+
+        self.record_proof(
+            &job_id,
+            proof,
+            ManagedBuffer::new(),
+            ManagedBuffer::new(),
+            agent_address,
+        );
     }
 
     /// NFT-holder variant: proves ownership by sending the agent NFT.
@@ -153,13 +420,16 @@ pub trait ValidationRegistry:
             ERR_INVALID_AGENT_NFT
         );
 
-        job_mapper.update(|job| {
-            job.proof = proof;
-            job.status = JobStatus::Pending;
-        });
+        let caller = self.blockchain().get_caller();
+        self.record_proof(
+            &job_id,
+            proof,
+            ManagedBuffer::new(),
+            ManagedBuffer::new(),
+            caller.clone(),
+        );
 
         // Return NFT to caller
-        let caller = self.blockchain().get_caller();
         self.tx()
             .to(&caller)
             .single_esdt(
@@ -170,8 +440,29 @@ pub trait ValidationRegistry:
             .transfer();
     }
 
+    /// Employer approves the validator whose response may verify the job. Required
+    /// before validation when the job holds a payment or has an active escrow, so the
+    /// agent owner cannot pick a validator that releases the funds.
+    #[endpoint(approve_validator)]
+    fn approve_validator(&self, job_id: ManagedBuffer, validator_address: ManagedAddress) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let job_data = job_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(caller == job_data.employer, ERR_NOT_EMPLOYER);
+        self.require_job_open(&job_data);
+        require!(
+            job_data.status != JobStatus::Verified,
+            ERR_JOB_ALREADY_VERIFIED
+        );
+
+        self.approved_validator(&job_id).set(validator_address);
+    }
+
     /// ERC-8004: Agent requests validation from a specific validator.
-    /// MUST be called by the owner of the agent (agentId).
+    /// MUST be called by the owner of the agent (agentId), once a proof was submitted and
+    /// before the job is verified.
     #[endpoint(validation_request)]
     fn validation_request(
         &self,
@@ -192,6 +483,17 @@ pub trait ValidationRegistry:
             .external_agents(identity_addr)
             .get_value(&job_data.agent_nonce);
         require!(caller == agent_owner, ERR_NOT_AGENT_OWNER);
+        // A verified job stays verified: re-opening it would take the employer's rating away
+        self.require_delivered(&job_data);
+        require!(
+            self.is_validator_approved(&job_id, &job_data, &validator_address),
+            ERR_VALIDATOR_NOT_APPROVED
+        );
+
+        // A request hash identifies one request for good: its responses are already
+        // counted in the summaries under the original validator and tag
+        let request_mapper = self.validation_request_data(&request_hash);
+        require!(request_mapper.is_empty(), ERR_VALIDATION_REQUEST_EXISTS);
 
         // Store validation request
         let request_data = ValidationRequestData {
@@ -204,10 +506,15 @@ pub trait ValidationRegistry:
             last_update: TimestampSeconds::new(0),
         };
 
-        self.validation_request_data(&request_hash)
-            .set(&request_data);
+        request_mapper.set(&request_data);
+        let now = self.blockchain().get_block_timestamp_millis();
+        self.update_job_timeline(&job_id, |timeline| {
+            if timeline.validation_requested_at == TimestampMillis::zero() {
+                timeline.validation_requested_at = now;
+            }
+        });
         self.agent_validations(job_data.agent_nonce)
-            .insert(request_hash.clone());
+            .push(&request_hash);
 
         // Update job status
         job_mapper.update(|job| {
@@ -224,13 +531,14 @@ pub trait ValidationRegistry:
 
     /// ERC-8004: Validator responds with a result (0-100).
     /// MUST be called by the validatorAddress from the original request.
-    /// Can be called multiple times for progressive validation.
+    /// Can be called multiple times for progressive validation; every call is
+    /// appended to the request's response history.
     #[endpoint(validation_response)]
     fn validation_response(
         &self,
         request_hash: ManagedBuffer,
         response: u8,
-        _response_uri: ManagedBuffer,
+        response_uri: ManagedBuffer,
         response_hash: ManagedBuffer,
         tag: ManagedBuffer,
     ) {
@@ -238,24 +546,50 @@ pub trait ValidationRegistry:
         require!(!request_mapper.is_empty(), ERR_VALIDATION_REQUEST_NOT_FOUND);
 
         let caller = self.blockchain().get_caller();
+        let timestamp = self.blockchain().get_block_timestamp_seconds();
+
+        let mut history_mapper = self.validation_responses(&request_hash);
+        let is_revision = !history_mapper.is_empty();
 
         request_mapper.update(|data| {
             require!(caller == data.validator_address, ERR_NOT_VALIDATOR);
 
+            // A revision replaces the request's previous contribution to the summaries
+            if is_revision {
+                self.update_validation_summary(
+                    data.agent_nonce,
+                    &caller,
+                    &data.tag,
+                    data.response,
+                    false,
+                );
+            }
+            self.update_validation_summary(data.agent_nonce, &caller, &tag, response, true);
+
             data.response = response;
-            data.response_hash = response_hash;
-            data.tag = tag;
-            data.last_update = self.blockchain().get_block_timestamp_seconds();
+            data.response_hash = response_hash.clone();
+            data.tag = tag.clone();
+            data.last_update = timestamp;
+        });
+
+        history_mapper.push(&ValidationResponseRecord {
+            response,
+            response_uri,
+            response_hash,
+            tag,
+            timestamp,
         });
 
         let updated_data = request_mapper.get();
 
-        // Transition job status to Verified
+        // A passing response from a validator the employer approved (required when
+        // funds are at stake) verifies the job and releases any held payment
         let job_mapper = self.job_data(&updated_data.job_id);
-        if !job_mapper.is_empty() {
-            job_mapper.update(|job| {
-                job.status = JobStatus::Verified;
-            });
+        if response >= MIN_PASSING_RESPONSE
+            && !job_mapper.is_empty()
+            && self.is_validator_approved(&updated_data.job_id, &job_mapper.get(), &caller)
+        {
+            self.mark_job_verified(&updated_data.job_id);
         }
 
         self.validation_response_event(
@@ -266,18 +600,44 @@ pub trait ValidationRegistry:
         );
     }
 
+    /// Deletes the given jobs once they are older than the retention configured for their
+    /// status. Jobs still referenced by an active escrow or awaiting feedback are skipped.
     #[endpoint(clean_old_jobs)]
     fn clean_old_jobs(&self, job_ids: MultiValueEncoded<ManagedBuffer>) {
         let current_time = self.blockchain().get_block_timestamp_millis();
+        let mut cleaned = ManagedVec::new();
         for job_id in job_ids {
             let job_mapper = self.job_data(&job_id);
             if job_mapper.is_empty() {
                 continue;
             }
             let job_data = job_mapper.get();
-            if current_time > job_data.creation_timestamp + THREE_DAYS {
-                job_mapper.clear();
+            let retention = self.get_job_retention_or_default(&job_data.status);
+            if current_time <= job_data.creation_timestamp + retention
+                || self.is_job_protected(&job_id, &job_data)
+            {
+                continue;
             }
+
+            job_mapper.clear();
+            self.remove_job_from_indexes(&job_id, &job_data);
+            self.job_proofs(&job_id).clear();
+            self.job_timeline(&job_id).clear();
+            self.job_requirements(&job_id).clear();
+            self.job_sla(&job_id).clear();
+            self.job_payment(&job_id).clear();
+            self.job_service_id(&job_id).clear();
+            self.optimistic_window(&job_id).clear();
+            self.approved_validator(&job_id).clear();
+            self.delivery_challenged(&job_id).clear();
+            self.disputes(&job_id).clear();
+            self.dispute_voters(&job_id).clear();
+            self.dispute_arbiters(&job_id).clear();
+            cleaned.push(job_id);
+        }
+
+        if !cleaned.is_empty() {
+            self.jobs_cleaned_event(self.blockchain().get_caller(), cleaned);
         }
     }
 }
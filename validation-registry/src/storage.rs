@@ -1,6 +1,9 @@
 multiversx_sc::imports!();
 
-use crate::structs::{JobData, ValidationRequestData};
+use crate::structs::{
+    AgentDeliveryMetrics, DisputeData, JobData, JobRequirements, JobStatus, JobTimeline,
+    ProofRecord, ValidationRequestData, ValidationResponseRecord, ValidationSummary,
+};
 
 #[multiversx_sc::module]
 pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
@@ -9,9 +12,114 @@ pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
     #[storage_mapper("jobData")]
     fn job_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<JobData<Self::Api>>;
 
+    /// Append-only history of every proof submitted for a job, oldest first.
+    #[storage_mapper("jobProofs")]
+    fn job_proofs(&self, job_id: &ManagedBuffer) -> VecMapper<ProofRecord<Self::Api>>;
+
+    /// Requirements committed at creation; kept out of `jobData`, whose layout other
+    /// contracts decode. Unset = none.
+    #[storage_mapper("jobRequirements")]
+    fn job_requirements(
+        &self,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<JobRequirements<Self::Api>>;
+
+    #[storage_mapper("jobTimeline")]
+    fn job_timeline(&self, job_id: &ManagedBuffer) -> SingleValueMapper<JobTimeline>;
+
+    /// Service SLA snapshotted from the identity registry when the job was created.
+    #[storage_mapper("jobSla")]
+    fn job_sla(&self, job_id: &ManagedBuffer) -> SingleValueMapper<DurationMillis>;
+
+    /// Read by the reputation registry.
+    #[storage_mapper("agentDeliveryMetrics")]
+    fn agent_delivery_metrics(&self, agent_nonce: u64) -> SingleValueMapper<AgentDeliveryMetrics>;
+
+    /// Set by the employer to opt into optimistic verification: the job auto-verifies
+    /// once this long has passed since the latest proof, unless challenged.
+    #[storage_mapper("optimisticWindow")]
+    fn optimistic_window(&self, job_id: &ManagedBuffer) -> SingleValueMapper<DurationMillis>;
+
+    #[view(is_delivery_challenged)]
+    #[storage_mapper("deliveryChallenged")]
+    fn delivery_challenged(&self, job_id: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    /// Validator the employer approved for the job; its passing response may verify a
+    /// job that holds funds.
+    #[storage_mapper("approvedValidator")]
+    fn approved_validator(&self, job_id: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    /// Job IDs per agent, in creation order. Append-only: cleanup leaves an empty
+    /// tombstone in place so page cursors stay valid.
+    #[storage_mapper("agentJobs")]
+    fn agent_jobs(&self, agent_nonce: u64) -> VecMapper<ManagedBuffer>;
+
+    /// 1-based index of the job in `agentJobs`; cleared together with the job.
+    #[storage_mapper("agentJobPosition")]
+    fn agent_job_position(&self, job_id: &ManagedBuffer) -> SingleValueMapper<usize>;
+
+    /// Job IDs per employer, same ordering rules as `agentJobs`.
+    #[storage_mapper("employerJobs")]
+    fn employer_jobs(&self, employer: &ManagedAddress) -> VecMapper<ManagedBuffer>;
+
+    /// 1-based index of the job in `employerJobs`; cleared together with the job.
+    #[storage_mapper("employerJobPosition")]
+    fn employer_job_position(&self, job_id: &ManagedBuffer) -> SingleValueMapper<usize>;
+
+    /// Last nonce used by `create_job` to generate an ID for this employer.
+    #[view(get_employer_job_nonce)]
+    #[storage_mapper("employerJobNonce")]
+    fn employer_job_nonce(&self, employer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Replay protection for `submit_proof_signed`: the nonce the agent's next signature
+    /// must cover.
+    #[view(get_agent_proof_nonce)]
+    #[storage_mapper("agentProofNonce")]
+    fn agent_proof_nonce(&self, agent_nonce: u64) -> SingleValueMapper<u64>;
+
     #[storage_mapper("identityRegistryAddress")]
     fn identity_registry_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Optional: when set, jobs with an active escrow are never cleaned.
+    #[view(get_escrow_contract_address)]
+    #[storage_mapper("escrowContractAddress")]
+    fn escrow_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Optional: when set, verified jobs still awaiting feedback are never cleaned.
+    #[view(get_reputation_contract_address)]
+    #[storage_mapper("reputationContractAddress")]
+    fn reputation_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Service payment held by the contract for jobs created with `init_job_held`.
+    /// Released to the agent owner on verification or refunded to the employer.
+    #[storage_mapper("heldPayment")]
+    fn held_payment(&self, job_id: &ManagedBuffer) -> SingleValueMapper<Payment<Self::Api>>;
+
+    /// Service the job was created for. Read by the reputation registry for per-service
+    /// scores.
+    #[storage_mapper("jobServiceId")]
+    fn job_service_id(&self, job_id: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    /// Service price paid at job creation, whether forwarded or held; any overpayment
+    /// is not counted. Read by the reputation registry to weight ratings.
+    #[storage_mapper("jobPayment")]
+    fn job_payment(&self, job_id: &ManagedBuffer) -> SingleValueMapper<Payment<Self::Api>>;
+
+    /// Time after the job's latest activity from which the employer may reclaim a held
+    /// payment.
+    /// Unset = `DEFAULT_REFUND_TIMEOUT`.
+    #[storage_mapper("refundTimeout")]
+    fn refund_timeout(&self) -> SingleValueMapper<DurationMillis>;
+
+    /// Minimum age before a job in `status` may be cleaned. Unset = `DEFAULT_JOB_RETENTION`.
+    #[storage_mapper("jobRetention")]
+    fn job_retention(&self, status: &JobStatus) -> SingleValueMapper<DurationMillis>;
+
+    /// How long after verification a job is kept for its employer's feedback.
+    /// Unset = `DEFAULT_FEEDBACK_WINDOW`.
+    #[storage_mapper("feedbackWindow")]
+    fn feedback_window(&self) -> SingleValueMapper<DurationMillis>;
+
     // ── ERC-8004 Validation storage ──
 
     #[storage_mapper("validationRequestData")]
@@ -20,6 +128,53 @@ pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
         request_hash: &ManagedBuffer,
     ) -> SingleValueMapper<ValidationRequestData<Self::Api>>;
 
+    /// Append-only history of every response submitted for a request hash.
+    #[storage_mapper("validationResponses")]
+    fn validation_responses(
+        &self,
+        request_hash: &ManagedBuffer,
+    ) -> VecMapper<ValidationResponseRecord<Self::Api>>;
+
+    /// Request hashes per agent, in request order. Append-only: request hashes are
+    /// never reused and are not removed by cleanup.
     #[storage_mapper("agentValidations")]
-    fn agent_validations(&self, agent_nonce: u64) -> UnorderedSetMapper<ManagedBuffer>;
+    fn agent_validations(&self, agent_nonce: u64) -> VecMapper<ManagedBuffer>;
+
+    /// Aggregated responses per `(agent, validator, tag)`. The zero address and the
+    /// empty tag act as "any validator" / "any tag" buckets.
+    #[storage_mapper("validationSummary")]
+    fn validation_summary(
+        &self,
+        agent_nonce: u64,
+        validator: &ManagedAddress,
+        tag: &ManagedBuffer,
+    ) -> SingleValueMapper<ValidationSummary>;
+
+    // ── Disputes ──
+
+    #[storage_mapper("disputes")]
+    fn disputes(&self, job_id: &ManagedBuffer) -> SingleValueMapper<DisputeData<Self::Api>>;
+
+    #[storage_mapper("disputeVoters")]
+    fn dispute_voters(&self, job_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// The arbiter panel as it was when the dispute was opened.
+    #[storage_mapper("disputeArbiters")]
+    fn dispute_arbiters(&self, job_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("arbiters")]
+    fn arbiters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Votes one side needs to win a dispute.
+    #[view(get_dispute_quorum)]
+    #[storage_mapper("disputeQuorum")]
+    fn dispute_quorum(&self) -> SingleValueMapper<u32>;
+
+    /// Bond required to open a dispute. Unset = no bond.
+    #[storage_mapper("disputeBond")]
+    fn dispute_bond(&self) -> SingleValueMapper<Payment<Self::Api>>;
+
+    /// Time arbiters have to decide a dispute. Unset = `DEFAULT_DISPUTE_PERIOD`.
+    #[storage_mapper("disputePeriod")]
+    fn dispute_period(&self) -> SingleValueMapper<DurationMillis>;
 }
@@ -1 +1,70 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
 pub use common::structs::*;
+
+/// Running totals for ERC-8004 `getSummary`-style aggregates.
+/// Each validation request contributes its latest response exactly once.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Default, PartialEq, Debug)]
+pub struct ValidationSummary {
+    pub count: u64,
+    pub response_sum: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum DisputeStatus {
+    Open,
+    ResolvedForAgent,
+    ResolvedForEmployer,
+    /// No side reached the quorum before the deadline: the job was cancelled.
+    Expired,
+}
+
+/// A dispute opened by the employer or the agent owner on a job.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct DisputeData<M: ManagedTypeApi> {
+    pub opener: ManagedAddress<M>,
+    pub reason_uri: ManagedBuffer<M>,
+    pub bond: Option<Payment<M>>,
+    pub opened_at: TimestampMillis,
+    /// Votes one side needs to win, fixed when the dispute is opened.
+    pub quorum: u32,
+    /// After this, the dispute can no longer be voted on and `expire_dispute` applies
+    /// the default outcome.
+    pub deadline: TimestampMillis,
+    pub votes_for_agent: u32,
+    pub votes_for_employer: u32,
+    pub status: DisputeStatus,
+}
+
+/// Requirements the employer committed to when creating the job.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobRequirements<M: ManagedTypeApi> {
+    pub hash: ManagedBuffer<M>,
+    pub uri: ManagedBuffer<M>,
+}
+
+/// One job for `init_jobs`. Empty `requirements_hash` / `requirements_uri` = none.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobInput<M: ManagedTypeApi> {
+    pub job_id: ManagedBuffer<M>,
+    pub agent_nonce: u64,
+    pub service_id: Option<u32>,
+    pub requirements_hash: ManagedBuffer<M>,
+    pub requirements_uri: ManagedBuffer<M>,
+}
+
+/// One proof for `submit_proofs`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ProofInput<M: ManagedTypeApi> {
+    pub job_id: ManagedBuffer<M>,
+    pub proof_hash: ManagedBuffer<M>,
+    pub uri: ManagedBuffer<M>,
+    pub content_type: ManagedBuffer<M>,
+}
@@ -0,0 +1,641 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::structs::{
+    AgentDeliveryMetrics, EscrowStatus, JobData, JobInput, JobRequirements, JobStatus, JobTimeline,
+    ProofRecord, ValidationSummary,
+};
+
+pub const DEFAULT_JOB_RETENTION: DurationMillis = DurationMillis::new(3 * 24 * 60 * 60 * 1000);
+pub const DEFAULT_REFUND_TIMEOUT: DurationMillis = DurationMillis::new(7 * 24 * 60 * 60 * 1000);
+pub const MAX_CHALLENGE_WINDOW: DurationMillis = DurationMillis::new(30 * 24 * 60 * 60 * 1000);
+/// Bounds a job's proof history, which `clean_old_jobs` clears in one go.
+pub const MAX_PROOFS_PER_JOB: usize = 20;
+/// Lowest validator response (out of 100) that verifies a job.
+pub const MIN_PASSING_RESPONSE: u8 = 50;
+pub const DEFAULT_FEEDBACK_WINDOW: DurationMillis = DurationMillis::new(30 * 24 * 60 * 60 * 1000);
+
+#[multiversx_sc::module]
+pub trait UtilsModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + crate::events::EventsModule
+{
+    /// Validates and stores a new job for `employer`. The service payment (if any) is
+    /// forwarded to the agent owner, or kept by the contract when `hold_payment` is set.
+    fn register_job(
+        &self,
+        job: JobInput<Self::Api>,
+        employer: &ManagedAddress,
+        payment: Option<Payment<Self::Api>>,
+        hold_payment: bool,
+    ) {
+        let job_id = &job.job_id;
+        let agent_nonce = job.agent_nonce;
+        let job_mapper = self.job_data(job_id);
+        require!(job_mapper.is_empty(), ERR_JOB_ALREADY_INITIALIZED);
+
+        let identity_addr = self.identity_registry_address().get();
+        let agents_mapper = self.external_agents(identity_addr.clone());
+        require!(agents_mapper.contains_id(&agent_nonce), ERR_AGENT_NOT_FOUND);
+
+        // If service_id provided, validate payment against the agent's service config.
+        // Without a service there is nothing to pay for, so any payment is rejected.
+        let payment_to_forward = match job.service_id {
+            Some(sid) => {
+                self.job_service_id(job_id).set(sid);
+
+                let service_payment = self
+                    .external_agent_service_config(identity_addr.clone(), agent_nonce)
+                    .get(&sid)
+                    .unwrap_or_else(|| sc_panic!(ERR_SERVICE_NOT_FOUND));
+
+                if let Some(sla) = self
+                    .external_agent_service_sla(identity_addr, agent_nonce)
+                    .get(&sid)
+                {
+                    self.job_sla(job_id).set(sla);
+                }
+
+                if let Some(pay) = payment {
+                    require!(
+                        pay.token_identifier == service_payment.token_identifier
+                            && pay.token_nonce == service_payment.token_nonce,
+                        ERR_INVALID_PAYMENT
+                    );
+
+                    require!(
+                        pay.amount >= service_payment.amount,
+                        ERR_INSUFFICIENT_PAYMENT
+                    );
+
+                    // Record the service price rather than the amount sent, so
+                    // overpaying cannot inflate the job's rating weight
+                    self.job_payment(job_id).set(Payment::new(
+                        pay.token_identifier.clone(),
+                        pay.token_nonce,
+                        service_payment.amount,
+                    ));
+                    Some(pay)
+                } else {
+                    // No payment sent — only valid if service is free
+                    require!(service_payment.amount == 0u64, ERR_INSUFFICIENT_PAYMENT);
+                    None
+                }
+            }
+            None => {
+                require!(payment.is_none(), ERR_PAYMENT_WITHOUT_SERVICE);
+                None
+            }
+        };
+
+        job_mapper.set(JobData {
+            status: JobStatus::New,
+            proof: ManagedBuffer::new(),
+            employer: employer.clone(),
+            creation_timestamp: self.blockchain().get_block_timestamp_millis(),
+            agent_nonce,
+        });
+        if !job.requirements_hash.is_empty() || !job.requirements_uri.is_empty() {
+            self.job_requirements(job_id).set(JobRequirements {
+                hash: job.requirements_hash.clone(),
+                uri: job.requirements_uri.clone(),
+            });
+        }
+        let agent_position = self.agent_jobs(agent_nonce).push(job_id);
+        self.agent_job_position(job_id).set(agent_position);
+        let employer_position = self.employer_jobs(employer).push(job_id);
+        self.employer_job_position(job_id).set(employer_position);
+        self.job_created_event(
+            job_id,
+            employer,
+            agent_nonce,
+            &job.requirements_hash,
+            &job.requirements_uri,
+        );
+
+        // Forward payment to agent owner, or hold it until the job is verified
+        if let Some(pay) = payment_to_forward {
+            if hold_payment {
+                self.held_payment(job_id).set(pay);
+            } else {
+                let agent_owner = agents_mapper.get_value(&agent_nonce);
+                self.tx().to(&agent_owner).payment(pay).transfer();
+            }
+        }
+    }
+
+    /// Whether a job for `service_id` must come with a payment: the service exists and
+    /// is not free. Unknown services are rejected later by `register_job`.
+    fn is_paid_service(&self, agent_nonce: u64, service_id: Option<u32>) -> bool {
+        let Some(sid) = service_id else {
+            return false;
+        };
+        self.external_agent_service_config(self.identity_registry_address().get(), agent_nonce)
+            .get(&sid)
+            .is_some_and(|price| price.amount != 0u64)
+    }
+
+    /// Checks that `caller` is the job's agent (by public key) or agent owner and that the
+    /// job still accepts proofs, then records the proof.
+    fn submit_proof_as(
+        &self,
+        caller: &ManagedAddress,
+        job_id: &ManagedBuffer,
+        proof_hash: ManagedBuffer,
+        uri: ManagedBuffer,
+        content_type: ManagedBuffer,
+    ) {
+        let job_mapper = self.job_data(job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let job_data = job_mapper.get();
+
+        // Security Check 1: Agent Owner or Registered Agent
+        let identity_addr = self.identity_registry_address().get();
+
+        // Optimization: Check Agent first (most frequent caller)
+        let agent_details = self
+            .external_agent_details(identity_addr.clone(), job_data.agent_nonce)
+            .get();
+
+        let mut is_authorized = false;
+        if caller.as_managed_buffer() == &agent_details.public_key {
+            is_authorized = true;
+        } else {
+            // Fallback: Check Agent Owner
+            let agent_owner = self
+                .external_agents(identity_addr)
+                .get_value(&job_data.agent_nonce);
+
+            if caller == &agent_owner {
+                is_authorized = true;
+            }
+        }
+        require!(is_authorized, ERR_NOT_AGENT_OWNER);
+
+        // Security Check 2: Ensure job is in correct state
+        require!(
+            job_data.status == JobStatus::New || job_data.status == JobStatus::Pending,
+            ERR_JOB_STATUS_INVALID
+        );
+
+        self.record_proof(job_id, proof_hash, uri, content_type, caller.clone());
+    }
+
+    /// Sets `proof_hash` as the job's current proof, moves it to `Pending` and appends the
+    /// submission to the job's proof history. Callers check authorization and status.
+    fn record_proof(
+        &self,
+        job_id: &ManagedBuffer,
+        proof_hash: ManagedBuffer,
+        uri: ManagedBuffer,
+        content_type: ManagedBuffer,
+        submitter: ManagedAddress,
+    ) {
+        let mut proofs = self.job_proofs(job_id);
+        require!(proofs.len() < MAX_PROOFS_PER_JOB, ERR_TOO_MANY_PROOFS);
+
+        self.job_data(job_id).update(|job| {
+            job.proof = proof_hash.clone();
+            job.status = JobStatus::Pending;
+        });
+
+        let now = self.blockchain().get_block_timestamp_millis();
+        self.update_job_timeline(job_id, |timeline| {
+            if timeline.first_proof_at == TimestampMillis::zero() {
+                timeline.first_proof_at = now;
+            }
+        });
+
+        let record = ProofRecord {
+            proof_hash,
+            uri,
+            content_type,
+            submitter,
+            timestamp: now,
+        };
+        self.proof_submitted_event(job_id, &record.submitter, &record);
+        proofs.push(&record);
+    }
+
+    /// Loads a job the employer wants to act on after delivery: caller must be the
+    /// employer and a proof must have been submitted.
+    fn require_employer_with_delivery(
+        &self,
+        job_id: &ManagedBuffer,
+        caller: &ManagedAddress,
+    ) -> JobData<Self::Api> {
+        let job_mapper = self.job_data(job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let job_data = job_mapper.get();
+        require!(caller == &job_data.employer, ERR_NOT_EMPLOYER);
+        self.require_delivered(&job_data);
+        job_data
+    }
+
+    /// Verification by the employer alone must not let an agent owner hire their own
+    /// agent and verify the job themselves.
+    fn require_employer_not_agent_owner(&self, job_data: &JobData<Self::Api>) {
+        let agent_owner = self
+            .external_agents(self.identity_registry_address().get())
+            .get_value(&job_data.agent_nonce);
+        require!(
+            job_data.employer != agent_owner,
+            ERR_EMPLOYER_IS_AGENT_OWNER
+        );
+    }
+
+    fn require_delivered(&self, job_data: &JobData<Self::Api>) {
+        self.require_job_open(job_data);
+        require!(
+            job_data.status != JobStatus::Verified,
+            ERR_JOB_ALREADY_VERIFIED
+        );
+        require!(
+            job_data.status == JobStatus::Pending
+                || job_data.status == JobStatus::ValidationRequested,
+            ERR_NO_DELIVERY
+        );
+    }
+
+    /// End of the optimistic challenge window, counted from the latest proof.
+    fn optimistic_deadline(&self, job_id: &ManagedBuffer) -> TimestampMillis {
+        let window_mapper = self.optimistic_window(job_id);
+        require!(!window_mapper.is_empty(), ERR_OPTIMISTIC_NOT_ENABLED);
+
+        let proofs = self.job_proofs(job_id);
+        require!(!proofs.is_empty(), ERR_NO_DELIVERY);
+        proofs.get(proofs.len()).timestamp + window_mapper.get()
+    }
+
+    /// Single place where a job becomes `Verified` (see `complete_verification`): also
+    /// pays out any held payment to the current agent owner.
+    fn mark_job_verified(&self, job_id: &ManagedBuffer) {
+        let job_mapper = self.job_data(job_id);
+        if job_mapper.is_empty() {
+            return;
+        }
+
+        let job = job_mapper.get();
+        self.require_job_open(&job);
+        self.complete_verification(job_id, job);
+    }
+
+    /// Rejects jobs that were cancelled, are under dispute or lost a dispute.
+    fn require_job_open(&self, job: &JobData<Self::Api>) {
+        require!(job.status != JobStatus::Cancelled, ERR_JOB_CANCELLED);
+        require!(job.status != JobStatus::Disputed, ERR_JOB_DISPUTED);
+        require!(job.status != JobStatus::Rejected, ERR_JOB_REJECTED);
+    }
+
+    /// Sets `Verified` without status guards (also used by dispute resolution).
+    fn complete_verification(&self, job_id: &ManagedBuffer, mut job: JobData<Self::Api>) {
+        job.status = JobStatus::Verified;
+        self.job_data(job_id).set(&job);
+
+        let timeline = self.get_job_timeline_or_default(job_id);
+        if timeline.verified_at == TimestampMillis::zero() {
+            self.record_delivery(job_id, &job, timeline);
+        }
+
+        let held_mapper = self.held_payment(job_id);
+        if held_mapper.is_empty() {
+            return;
+        }
+        let payment = held_mapper.take();
+        let identity_addr = self.identity_registry_address().get();
+        let agent_owner = self
+            .external_agents(identity_addr)
+            .get_value(&job.agent_nonce);
+        self.tx().to(&agent_owner).payment(&payment).transfer();
+        self.held_payment_released_event(job_id, &agent_owner, &payment);
+    }
+
+    /// Stamps the first verification and folds the job into its agent's delivery metrics.
+    /// Jobs verified without a proof count as delivered at verification time.
+    fn record_delivery(
+        &self,
+        job_id: &ManagedBuffer,
+        job: &JobData<Self::Api>,
+        mut timeline: JobTimeline,
+    ) {
+        let now = self.blockchain().get_block_timestamp_millis();
+        timeline.verified_at = now;
+
+        let delivered_at = if timeline.first_proof_at == TimestampMillis::zero() {
+            now
+        } else {
+            timeline.first_proof_at
+        };
+        let delivery_time = delivered_at - job.creation_timestamp;
+        self.job_timeline(job_id).set(timeline);
+
+        let sla_mapper = self.job_sla(job_id);
+        let metrics_mapper = self.agent_delivery_metrics(job.agent_nonce);
+        let mut metrics = self.get_delivery_metrics_or_default(&metrics_mapper);
+        metrics.delivered_jobs += 1;
+        metrics.total_delivery_ms += delivery_time.as_u64_millis();
+        if !sla_mapper.is_empty() {
+            metrics.sla_jobs += 1;
+            if delivery_time <= sla_mapper.get() {
+                metrics.on_time_jobs += 1;
+            }
+        }
+        metrics_mapper.set(metrics);
+    }
+
+    fn update_job_timeline<F: FnOnce(&mut JobTimeline)>(&self, job_id: &ManagedBuffer, f: F) {
+        let mut timeline = self.get_job_timeline_or_default(job_id);
+        f(&mut timeline);
+        self.job_timeline(job_id).set(timeline);
+    }
+
+    fn get_job_timeline_or_default(&self, job_id: &ManagedBuffer) -> JobTimeline {
+        let mapper = self.job_timeline(job_id);
+        if mapper.is_empty() {
+            JobTimeline::default()
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_delivery_metrics_or_default(
+        &self,
+        mapper: &SingleValueMapper<AgentDeliveryMetrics>,
+    ) -> AgentDeliveryMetrics {
+        if mapper.is_empty() {
+            AgentDeliveryMetrics::default()
+        } else {
+            mapper.get()
+        }
+    }
+
+    /// Marks the job `Cancelled` and returns any held payment to the employer.
+    fn cancel_and_refund(&self, job_id: &ManagedBuffer, employer: &ManagedAddress) {
+        self.job_data(job_id)
+            .update(|job| job.status = JobStatus::Cancelled);
+        self.refund_held_payment(job_id, employer);
+        self.job_cancelled_event(job_id, employer);
+    }
+
+    fn refund_held_payment(&self, job_id: &ManagedBuffer, employer: &ManagedAddress) {
+        let held_mapper = self.held_payment(job_id);
+        if !held_mapper.is_empty() {
+            let payment = held_mapper.take();
+            self.tx().to(employer).payment(&payment).transfer();
+            self.held_payment_refunded_event(job_id, employer, &payment);
+        }
+    }
+
+    /// Latest of the job's creation, its latest proof and its first validation request: a
+    /// delivery in progress restarts the refund timeout.
+    fn last_job_activity(
+        &self,
+        job_id: &ManagedBuffer,
+        job_data: &JobData<Self::Api>,
+    ) -> TimestampMillis {
+        let mut last_activity = core::cmp::max(
+            job_data.creation_timestamp,
+            self.get_job_timeline_or_default(job_id)
+                .validation_requested_at,
+        );
+        let proofs = self.job_proofs(job_id);
+        if !proofs.is_empty() {
+            last_activity = core::cmp::max(last_activity, proofs.get(proofs.len()).timestamp);
+        }
+        last_activity
+    }
+
+    fn get_refund_timeout_or_default(&self) -> DurationMillis {
+        let mapper = self.refund_timeout();
+        if mapper.is_empty() {
+            DEFAULT_REFUND_TIMEOUT
+        } else {
+            mapper.get()
+        }
+    }
+
+    /// Picks the next unused generated job ID for `employer`.
+    fn generate_job_id(&self, employer: &ManagedAddress) -> ManagedBuffer {
+        let block_nonce = self.blockchain().get_block_nonce();
+        loop {
+            let nonce = self.employer_job_nonce(employer).update(|n| {
+                *n += 1;
+                *n
+            });
+            let job_id = self.compute_job_id(employer, nonce, block_nonce);
+            if self.job_data(&job_id).is_empty() {
+                return job_id;
+            }
+        }
+    }
+
+    /// `sha256(employer || nonce || block_nonce)`, integers big-endian.
+    fn compute_job_id(
+        &self,
+        employer: &ManagedAddress,
+        nonce: u64,
+        block_nonce: u64,
+    ) -> ManagedBuffer {
+        let mut seed = employer.as_managed_buffer().clone();
+        seed.append_bytes(&nonce.to_be_bytes());
+        seed.append_bytes(&block_nonce.to_be_bytes());
+        self.crypto().sha256(&seed).as_managed_buffer().clone()
+    }
+
+    /// `sha256(contract || job_id_len || job_id || nonce || expiry || proof)`, integers
+    /// big-endian (`job_id_len` as u32), signed by the agent for `submit_proof_signed`.
+    fn compute_proof_signing_message(
+        &self,
+        job_id: &ManagedBuffer,
+        proof: &ManagedBuffer,
+        nonce: u64,
+        expiry: TimestampMillis,
+    ) -> ManagedBuffer {
+        let mut payload = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        payload.append_bytes(&(job_id.len() as u32).to_be_bytes());
+        payload.append(job_id);
+        payload.append_bytes(&nonce.to_be_bytes());
+        payload.append_bytes(&expiry.as_u64_millis().to_be_bytes());
+        payload.append(proof);
+        self.crypto().sha256(&payload).as_managed_buffer().clone()
+    }
+
+    fn get_job_retention_or_default(&self, status: &JobStatus) -> DurationMillis {
+        let mapper = self.job_retention(status);
+        if mapper.is_empty() {
+            DEFAULT_JOB_RETENTION
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn get_feedback_window_or_default(&self) -> DurationMillis {
+        let mapper = self.feedback_window();
+        if mapper.is_empty() {
+            DEFAULT_FEEDBACK_WINDOW
+        } else {
+            mapper.get()
+        }
+    }
+
+    /// A job must be kept while it holds a payment or is disputed, while an escrow still
+    /// depends on it or while a rating on it is still owed: the employer's feedback on a
+    /// `Verified` job, or the agent owner's `rate_employer` on a `Verified`, `Rejected` or
+    /// delivered `Cancelled` job. Ratings are waited for at most the feedback window after
+    /// the job's verification or last activity. The escrow / rating checks are skipped
+    /// when the related contract is not configured.
+    fn is_job_protected(&self, job_id: &ManagedBuffer, job_data: &JobData<Self::Api>) -> bool {
+        if !self.held_payment(job_id).is_empty() || job_data.status == JobStatus::Disputed {
+            return true;
+        }
+
+        if self.has_active_escrow(job_id, job_data) {
+            return true;
+        }
+
+        let reputation_addr_mapper = self.reputation_contract_address();
+        if reputation_addr_mapper.is_empty() {
+            return false;
+        }
+
+        // Mirrors the statuses the reputation registry's `rate_employer` accepts
+        let employer_rateable = match job_data.status {
+            JobStatus::Verified | JobStatus::Rejected => true,
+            JobStatus::Cancelled => !self.job_proofs(job_id).is_empty(),
+            _ => false,
+        };
+        if !employer_rateable {
+            return false;
+        }
+
+        let verified_at = self.get_job_timeline_or_default(job_id).verified_at;
+        let feedback_deadline =
+            core::cmp::max(verified_at, self.last_job_activity(job_id, job_data))
+                + self.get_feedback_window_or_default();
+        if self.blockchain().get_block_timestamp_millis() > feedback_deadline {
+            return false;
+        }
+
+        let reputation_addr = reputation_addr_mapper.get();
+        if job_data.status == JobStatus::Verified
+            && !self
+                .external_has_given_feedback(reputation_addr.clone(), job_id)
+                .get()
+        {
+            return true;
+        }
+        !self
+            .external_has_rated_employer(reputation_addr, job_id)
+            .get()
+    }
+
+    /// Whether `validator`'s responses may verify the job: always for jobs without funds
+    /// at stake, otherwise only for the validator the employer approved.
+    fn is_validator_approved(
+        &self,
+        job_id: &ManagedBuffer,
+        job_data: &JobData<Self::Api>,
+        validator: &ManagedAddress,
+    ) -> bool {
+        if self.held_payment(job_id).is_empty() && !self.has_active_escrow(job_id, job_data) {
+            return true;
+        }
+        let approved_mapper = self.approved_validator(job_id);
+        !approved_mapper.is_empty() && &approved_mapper.get() == validator
+    }
+
+    /// Whether the escrow contract (when configured) still holds funds for the job, paid
+    /// by its employer to its agent owner. Escrows anyone else deposited under the job ID
+    /// are ignored, so they cannot pin or dispute the job.
+    fn has_active_escrow(&self, job_id: &ManagedBuffer, job_data: &JobData<Self::Api>) -> bool {
+        let escrow_addr_mapper = self.escrow_contract_address();
+        if escrow_addr_mapper.is_empty() {
+            return false;
+        }
+        let escrow_mapper = self.external_escrow_data(escrow_addr_mapper.get(), job_id);
+        if escrow_mapper.is_empty() {
+            return false;
+        }
+        let escrow = escrow_mapper.get();
+        escrow.status == EscrowStatus::Active
+            && escrow.employer == job_data.employer
+            && escrow.receiver == self.agent_owner_of(job_data)
+    }
+
+    fn agent_owner_of(&self, job_data: &JobData<Self::Api>) -> ManagedAddress {
+        let identity_addr = self.identity_registry_address().get();
+        self.external_agents(identity_addr)
+            .get_value(&job_data.agent_nonce)
+    }
+
+    /// Tombstones the job in the agent and employer indexes instead of removing it,
+    /// so the positions of the remaining entries do not move.
+    fn remove_job_from_indexes(&self, job_id: &ManagedBuffer, job_data: &JobData<Self::Api>) {
+        let agent_position = self.agent_job_position(job_id).take();
+        self.agent_jobs(job_data.agent_nonce)
+            .set(agent_position, &ManagedBuffer::new());
+        let employer_position = self.employer_job_position(job_id).take();
+        self.employer_jobs(&job_data.employer)
+            .set(employer_position, &ManagedBuffer::new());
+    }
+
+    /// Add (or, when `add` is false, remove) one response from every summary bucket it
+    /// belongs to: `(validator, tag)`, `(validator, any tag)`, `(any validator, tag)` and
+    /// `(any validator, any tag)`. "Any" is keyed by the zero address / empty tag.
+    fn update_validation_summary(
+        &self,
+        agent_nonce: u64,
+        validator: &ManagedAddress,
+        tag: &ManagedBuffer,
+        response: u8,
+        add: bool,
+    ) {
+        let any_validator = ManagedAddress::zero();
+        let any_tag = ManagedBuffer::new();
+
+        for bucket_validator in [validator, &any_validator] {
+            self.update_summary_bucket(agent_nonce, bucket_validator, &any_tag, response, add);
+            if !tag.is_empty() {
+                self.update_summary_bucket(agent_nonce, bucket_validator, tag, response, add);
+            }
+        }
+    }
+
+    fn update_summary_bucket(
+        &self,
+        agent_nonce: u64,
+        validator: &ManagedAddress,
+        tag: &ManagedBuffer,
+        response: u8,
+        add: bool,
+    ) {
+        let mapper = self.validation_summary(agent_nonce, validator, tag);
+        let mut summary = self.get_summary_or_default(&mapper);
+        if add {
+            summary.count += 1;
+            summary.response_sum += response as u64;
+        } else {
+            summary.count -= 1;
+            summary.response_sum -= response as u64;
+        }
+        mapper.set(summary);
+    }
+
+    fn get_summary_or_default(
+        &self,
+        mapper: &SingleValueMapper<ValidationSummary>,
+    ) -> ValidationSummary {
+        if mapper.is_empty() {
+            ValidationSummary::default()
+        } else {
+            mapper.get()
+        }
+    }
+}
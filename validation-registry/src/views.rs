@@ -1,11 +1,21 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::{JobData, ValidationRequestData};
+use common::pagination::page_range;
+
+use crate::errors::ERR_JOB_NOT_FOUND;
+
+use crate::structs::{
+    AgentDeliveryMetrics, JobData, JobStatus, JobTimeline, ProofRecord, ValidationRequestData,
+    ValidationResponseRecord,
+};
 
 #[multiversx_sc::module]
 pub trait ViewsModule:
-    common::cross_contract::CrossContractModule + crate::storage::ExternalStorageModule
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
 {
     #[view(is_job_verified)]
     fn is_job_verified(&self, job_id: ManagedBuffer) -> bool {
@@ -13,6 +23,148 @@ pub trait ViewsModule:
         !job_mapper.is_empty() && job_mapper.get().status == crate::structs::JobStatus::Verified
     }
 
+    /// Retention applied by `clean_old_jobs` to jobs in `status`.
+    #[view(get_job_retention)]
+    fn get_job_retention(&self, status: JobStatus) -> DurationMillis {
+        self.get_job_retention_or_default(&status)
+    }
+
+    /// Time after a job ends during which it is kept while awaiting a rating.
+    #[view(get_feedback_window)]
+    fn get_feedback_window(&self) -> DurationMillis {
+        self.get_feedback_window_or_default()
+    }
+
+    #[view(get_job_proof_count)]
+    fn get_job_proof_count(&self, job_id: ManagedBuffer) -> usize {
+        self.job_proofs(&job_id).len()
+    }
+
+    /// Paginated proof history for a job, oldest first.
+    /// `from` is a 0-based offset; at most `MAX_PAGE_SIZE` entries are returned.
+    #[view(get_job_proofs)]
+    fn get_job_proofs(
+        &self,
+        job_id: ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<ProofRecord<Self::Api>> {
+        let mapper = self.job_proofs(&job_id);
+        let (start, end) = page_range(from, size, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(mapper.get(index + 1));
+        }
+        result
+    }
+
+    #[view(get_job_timeline)]
+    fn get_job_timeline(&self, job_id: ManagedBuffer) -> JobTimeline {
+        self.get_job_timeline_or_default(&job_id)
+    }
+
+    #[view(get_job_sla)]
+    fn get_job_sla(&self, job_id: ManagedBuffer) -> OptionalValue<DurationMillis> {
+        let mapper = self.job_sla(&job_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(get_agent_delivery_metrics)]
+    fn get_agent_delivery_metrics(&self, agent_nonce: u64) -> AgentDeliveryMetrics {
+        self.get_delivery_metrics_or_default(&self.agent_delivery_metrics(agent_nonce))
+    }
+
+    /// `(delivered_jobs, average_delivery_ms, on_time_ratio_bps)` — the ratio only
+    /// counts jobs whose service had an SLA.
+    #[view(get_agent_delivery_stats)]
+    fn get_agent_delivery_stats(&self, agent_nonce: u64) -> MultiValue3<u64, u64, u64> {
+        let metrics = self.get_agent_delivery_metrics(agent_nonce);
+        (
+            metrics.delivered_jobs,
+            metrics.average_delivery_ms(),
+            metrics.on_time_ratio_bps(),
+        )
+            .into()
+    }
+
+    #[view(get_optimistic_window)]
+    fn get_optimistic_window(&self, job_id: ManagedBuffer) -> OptionalValue<DurationMillis> {
+        let mapper = self.optimistic_window(&job_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(get_approved_validator)]
+    fn get_approved_validator(&self, job_id: ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        let mapper = self.approved_validator(&job_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(get_job_service_id)]
+    fn get_job_service_id(&self, job_id: ManagedBuffer) -> OptionalValue<u32> {
+        let mapper = self.job_service_id(&job_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    /// Service price paid for the job at creation; `None` for unpaid jobs.
+    #[view(get_job_payment)]
+    fn get_job_payment(&self, job_id: ManagedBuffer) -> OptionalValue<Payment<Self::Api>> {
+        let mapper = self.job_payment(&job_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(get_held_payment)]
+    fn get_held_payment(&self, job_id: ManagedBuffer) -> OptionalValue<Payment<Self::Api>> {
+        let mapper = self.held_payment(&job_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    /// Time after the job's latest activity from which `claim_refund` is allowed.
+    #[view(get_refund_timeout)]
+    fn get_refund_timeout(&self) -> DurationMillis {
+        self.get_refund_timeout_or_default()
+    }
+
+    /// Message the agent must sign for `submit_proof_signed`, bound to its current
+    /// proof nonce.
+    #[view(get_proof_signing_message)]
+    fn get_proof_signing_message(
+        &self,
+        job_id: ManagedBuffer,
+        proof: ManagedBuffer,
+        expiry: TimestampMillis,
+    ) -> ManagedBuffer {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let nonce = self.agent_proof_nonce(job_mapper.get().agent_nonce).get();
+        self.compute_proof_signing_message(&job_id, &proof, nonce, expiry)
+    }
+
     #[view(get_job_data)]
     fn get_job_data(&self, job_id: ManagedBuffer) -> OptionalValue<JobData<Self::Api>> {
         let job_mapper = self.job_data(&job_id);
@@ -23,7 +175,23 @@ pub trait ViewsModule:
         }
     }
 
-    /// ERC-8004: Returns validation status for a request hash.
+    /// `(requirements_hash, requirements_uri)` committed by the employer; both empty when
+    /// the job was created without requirements.
+    #[view(get_job_requirements)]
+    fn get_job_requirements(
+        &self,
+        job_id: ManagedBuffer,
+    ) -> MultiValue2<ManagedBuffer, ManagedBuffer> {
+        require!(!self.job_data(&job_id).is_empty(), ERR_JOB_NOT_FOUND);
+
+        let requirements_mapper = self.job_requirements(&job_id);
+        if requirements_mapper.is_empty() {
+            return (ManagedBuffer::new(), ManagedBuffer::new()).into();
+        }
+        let requirements = requirements_mapper.get();
+        (requirements.hash, requirements.uri).into()
+    }
+
     #[view(get_validation_status)]
     fn get_validation_status(
         &self,
@@ -38,6 +206,7 @@ pub trait ViewsModule:
     }
 
     /// ERC-8004: Returns all validation request hashes for an agent.
+    /// Unbounded — prefer `get_agent_validations_page` for agents with many requests.
     #[view(get_agent_validations)]
     fn get_agent_validations(&self, agent_nonce: u64) -> ManagedVec<ManagedBuffer> {
         let mut result = ManagedVec::new();
@@ -46,4 +215,215 @@ pub trait ViewsModule:
         }
         result
     }
+
+    /// Number of responses recorded for a request hash (progressive validation).
+    #[view(get_validation_response_count)]
+    fn get_validation_response_count(&self, request_hash: ManagedBuffer) -> usize {
+        self.validation_responses(&request_hash).len()
+    }
+
+    /// Latest response recorded for a request hash, including its `response_uri`, which
+    /// the request itself does not store.
+    #[view(get_latest_validation_response)]
+    fn get_latest_validation_response(
+        &self,
+        request_hash: ManagedBuffer,
+    ) -> OptionalValue<ValidationResponseRecord<Self::Api>> {
+        let mapper = self.validation_responses(&request_hash);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get(mapper.len()))
+        }
+    }
+
+    /// Paginated response history for a request hash, oldest first.
+    /// `from` is a 0-based offset; at most `MAX_PAGE_SIZE` entries are returned.
+    #[view(get_validation_responses)]
+    fn get_validation_responses(
+        &self,
+        request_hash: ManagedBuffer,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<ValidationResponseRecord<Self::Api>> {
+        let mapper = self.validation_responses(&request_hash);
+        let (start, end) = page_range(from, size, mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(mapper.get(index + 1));
+        }
+        result
+    }
+
+    /// ERC-8004 `getSummary`: number of responded requests for an agent and their average
+    /// response, optionally filtered by validator set and tag. An empty `tag` or an empty
+    /// validator list means no filter. Validators should be distinct.
+    #[view(get_validation_summary)]
+    fn get_validation_summary(
+        &self,
+        agent_nonce: u64,
+        tag: ManagedBuffer,
+        validator_addresses: MultiValueEncoded<ManagedAddress>,
+    ) -> MultiValue2<u64, u8> {
+        let mut count = 0u64;
+        let mut response_sum = 0u64;
+
+        if validator_addresses.is_empty() {
+            let summary = self.get_summary_or_default(&self.validation_summary(
+                agent_nonce,
+                &ManagedAddress::zero(),
+                &tag,
+            ));
+            count = summary.count;
+            response_sum = summary.response_sum;
+        } else {
+            for validator in validator_addresses {
+                let summary = self.get_summary_or_default(&self.validation_summary(
+                    agent_nonce,
+                    &validator,
+                    &tag,
+                ));
+                count += summary.count;
+                response_sum += summary.response_sum;
+            }
+        }
+
+        let average = response_sum.checked_div(count).unwrap_or(0) as u8;
+        (count, average).into()
+    }
+
+    #[view(get_agent_validation_count)]
+    fn get_agent_validation_count(&self, agent_nonce: u64) -> usize {
+        self.agent_validations(agent_nonce).len()
+    }
+
+    /// Cursor-based page over an agent's validation request hashes, in request order.
+    /// Scans at most `size` entries starting at `from` and returns the matching hashes
+    /// together with the cursor to resume from; the scan is complete once the cursor
+    /// equals `get_agent_validation_count`.
+    /// Optional trailing filters: `validator`, `tag` (of the latest response) and `responded`.
+    /// As they are positional, the zero address and the empty tag mean "any".
+    #[view(get_agent_validations_page)]
+    #[allow_multiple_var_args]
+    fn get_agent_validations_page(
+        &self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+        validator: OptionalValue<ManagedAddress>,
+        tag: OptionalValue<ManagedBuffer>,
+        responded: OptionalValue<bool>,
+    ) -> MultiValue2<usize, ManagedVec<ManagedBuffer>> {
+        let validator = validator.into_option().filter(|address| !address.is_zero());
+        let tag = tag.into_option().filter(|tag| !tag.is_empty());
+        let responded = responded.into_option();
+        let mapper = self.agent_validations(agent_nonce);
+        let (start, end) = page_range(from, size, mapper.len());
+
+        let mut result = ManagedVec::new();
+        for index in start..end {
+            let request_hash = mapper.get(index + 1);
+            let data = self.validation_request_data(&request_hash).get();
+            let has_response = !self.validation_responses(&request_hash).is_empty();
+
+            if validator
+                .as_ref()
+                .is_some_and(|expected| &data.validator_address != expected)
+            {
+                continue;
+            }
+            if tag
+                .as_ref()
+                .is_some_and(|expected| !has_response || &data.tag != expected)
+            {
+                continue;
+            }
+            if responded.is_some_and(|expected| has_response != expected) {
+                continue;
+            }
+            result.push(request_hash);
+        }
+        (end, result).into()
+    }
+
+    /// Number of entries in the agent's job index, including tombstones left by cleanup;
+    /// this is the cursor bound for `get_agent_jobs_page`.
+    #[view(get_agent_job_count)]
+    fn get_agent_job_count(&self, agent_nonce: u64) -> usize {
+        self.agent_jobs(agent_nonce).len()
+    }
+
+    /// Cursor-based page over an agent's job IDs, in creation order.
+    /// Returns the job IDs (optionally only those in `status`) and the cursor to resume from.
+    /// Cleaned-up jobs are skipped, so a page may hold fewer than `size` IDs.
+    #[view(get_agent_jobs_page)]
+    fn get_agent_jobs_page(
+        &self,
+        agent_nonce: u64,
+        from: usize,
+        size: usize,
+        status: OptionalValue<JobStatus>,
+    ) -> MultiValue2<usize, ManagedVec<ManagedBuffer>> {
+        self.job_ids_page(
+            self.agent_jobs(agent_nonce),
+            |job_id| self.agent_job_position(job_id).get(),
+            from,
+            size,
+            status.into_option(),
+        )
+    }
+
+    /// Same as `get_agent_job_count`, for the employer's job index.
+    #[view(get_employer_job_count)]
+    fn get_employer_job_count(&self, employer: ManagedAddress) -> usize {
+        self.employer_jobs(&employer).len()
+    }
+
+    /// Cursor-based page over the jobs created by an employer, in creation order.
+    #[view(get_employer_jobs_page)]
+    fn get_employer_jobs_page(
+        &self,
+        employer: ManagedAddress,
+        from: usize,
+        size: usize,
+        status: OptionalValue<JobStatus>,
+    ) -> MultiValue2<usize, ManagedVec<ManagedBuffer>> {
+        self.job_ids_page(
+            self.employer_jobs(&employer),
+            |job_id| self.employer_job_position(job_id).get(),
+            from,
+            size,
+            status.into_option(),
+        )
+    }
+
+    /// An entry is live only while the job's stored position still points at it;
+    /// tombstones and entries of a cleaned-up ID that was later reused never match.
+    fn job_ids_page<F: Fn(&ManagedBuffer) -> usize>(
+        &self,
+        mapper: VecMapper<ManagedBuffer>,
+        position_of: F,
+        from: usize,
+        size: usize,
+        status: Option<JobStatus>,
+    ) -> MultiValue2<usize, ManagedVec<ManagedBuffer>> {
+        let (start, end) = page_range(from, size, mapper.len());
+
+        let mut result = ManagedVec::new();
+        for index in start..end {
+            let job_id = mapper.get(index + 1);
+            if position_of(&job_id) != index + 1 {
+                continue;
+            }
+            if status
+                .as_ref()
+                .is_some_and(|expected| &self.job_data(&job_id).get().status != expected)
+            {
+                continue;
+            }
+            result.push(job_id);
+        }
+        (end, result).into()
+    }
 }
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
+// Endpoints:                           76
 // Async Callback (empty):               1
-// Total number of exported functions:  14
+// Total number of exported functions:  79
 
 #![no_std]
 
@@ -21,16 +21,81 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         init_job => init_job
+        init_job_with_requirements => init_job_with_requirements
+        init_jobs => init_jobs
+        create_job => create_job
+        create_job_with_requirements => create_job_with_requirements
+        init_job_held => init_job_held
+        init_job_held_with_requirements => init_job_held_with_requirements
+        cancel_job => cancel_job
+        claim_refund => claim_refund
+        accept_delivery => accept_delivery
+        enable_optimistic_verification => enable_optimistic_verification
+        challenge_delivery => challenge_delivery
+        finalize_job => finalize_job
         submit_proof => submit_proof
+        submit_proof_with_details => submit_proof_with_details
+        submit_proofs => submit_proofs
+        submit_proof_signed => submit_proof_signed
         submit_proof_with_nft => submit_proof_with_nft
+        approve_validator => approve_validator
         validation_request => validation_request
         validation_response => validation_response
         clean_old_jobs => clean_old_jobs
+        is_delivery_challenged => delivery_challenged
+        get_employer_job_nonce => employer_job_nonce
+        get_agent_proof_nonce => agent_proof_nonce
+        get_escrow_contract_address => escrow_contract_address
+        get_reputation_contract_address => reputation_contract_address
+        get_dispute_quorum => dispute_quorum
         is_job_verified => is_job_verified
+        get_job_retention => get_job_retention
+        get_feedback_window => get_feedback_window
+        get_job_proof_count => get_job_proof_count
+        get_job_proofs => get_job_proofs
+        get_job_timeline => get_job_timeline
+        get_job_sla => get_job_sla
+        get_agent_delivery_metrics => get_agent_delivery_metrics
+        get_agent_delivery_stats => get_agent_delivery_stats
+        get_optimistic_window => get_optimistic_window
+        get_approved_validator => get_approved_validator
+        get_job_service_id => get_job_service_id
+        get_job_payment => get_job_payment
+        get_held_payment => get_held_payment
+        get_refund_timeout => get_refund_timeout
+        get_proof_signing_message => get_proof_signing_message
         get_job_data => get_job_data
+        get_job_requirements => get_job_requirements
         get_validation_status => get_validation_status
         get_agent_validations => get_agent_validations
+        get_validation_response_count => get_validation_response_count
+        get_latest_validation_response => get_latest_validation_response
+        get_validation_responses => get_validation_responses
+        get_validation_summary => get_validation_summary
+        get_agent_validation_count => get_agent_validation_count
+        get_agent_validations_page => get_agent_validations_page
+        get_agent_job_count => get_agent_job_count
+        get_agent_jobs_page => get_agent_jobs_page
+        get_employer_job_count => get_employer_job_count
+        get_employer_jobs_page => get_employer_jobs_page
         set_identity_registry_address => set_identity_registry_address
+        set_escrow_contract_address => set_escrow_contract_address
+        set_reputation_contract_address => set_reputation_contract_address
+        set_job_retention => set_job_retention
+        set_refund_timeout => set_refund_timeout
+        set_feedback_window => set_feedback_window
+        open_dispute => open_dispute
+        vote_dispute => vote_dispute
+        expire_dispute => expire_dispute
+        add_arbiters => add_arbiters
+        remove_arbiters => remove_arbiters
+        set_dispute_quorum => set_dispute_quorum
+        set_dispute_period => set_dispute_period
+        set_dispute_bond => set_dispute_bond
+        get_dispute => get_dispute
+        get_dispute_period => get_dispute_period
+        get_arbiters => get_arbiters
+        get_dispute_bond => get_dispute_bond
     )
 }
 